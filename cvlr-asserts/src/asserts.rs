@@ -11,7 +11,7 @@ macro_rules! impl_bin_assert {
             cvlr::clog!(__cvlr_lhs => stringify!($lhs));
             cvlr::clog!(__cvlr_rhs => stringify!($rhs));
             cvlr::log::log_scope_end("assert");
-            $crate::cvlr_assert!(__cvlr_lhs $pred __cvlr_rhs);
+            $crate::cvlr_assert!(__cvlr_lhs $pred __cvlr_rhs $dollar(, $desc)?);
         }};
     }
         pub use $name;
@@ -38,7 +38,7 @@ macro_rules! impl_bin_assume {
             cvlr::clog!(__cvlr_lhs => stringify!($lhs));
             cvlr::clog!(__cvlr_rhs => stringify!($rhs));
             cvlr::log::log_scope_end("assume");
-            $crate::cvlr_assume!(__cvlr_lhs $pred __cvlr_rhs);
+            $crate::cvlr_assume!(__cvlr_lhs $pred __cvlr_rhs $dollar(, $desc)?);
         }};
     }
         pub use $name;
@@ -54,9 +54,9 @@ impl_bin_assume!(cvlr_assume_gt, >, $);
 
 #[macro_export]
 macro_rules! cvlr_assert_if {
-    ($guard: expr, $cond: expr) => {
+    ($guard: expr, $cond: expr $(, $desc: literal)?) => {
         if $guard {
-            $crate::cvlr_assert!($cond);
+            $crate::cvlr_assert!($cond $(, $desc)?);
         }
     };
 }
@@ -75,7 +75,7 @@ macro_rules! impl_bin_assert_if {
                 let __cvlr_rhs = $rhs;
                 cvlr::clog!(__cvlr_lhs => stringify!($lhs));
                 cvlr::clog!(__cvlr_rhs => stringify!($rhs));
-                $crate::cvlr_assert!(__cvlr_lhs $pred __cvlr_rhs);
+                $crate::cvlr_assert!(__cvlr_lhs $pred __cvlr_rhs $dollar(, $desc)?);
             }
         }};
     }
@@ -1,5 +1,6 @@
+// -- C-unwind so that rt failures unwind into the caller instead of aborting
 mod rt_decls {
-    extern "C" {
+    extern "C-unwind" {
         pub fn CVT_assume(_c: bool);
         pub fn CVT_assert(_c: bool);
         pub fn CVT_satisfy(_c: bool);
@@ -7,28 +8,63 @@ mod rt_decls {
     }
 }
 
+/// Panic payload of a violated assumption under `rt`
+///
+/// A run that violates an assumption is not a counterexample and should be
+/// discarded, rather than be reported as a failure.
+#[cfg(feature = "rt")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssumptionViolated {
+    /// Description given to the assume macro, if any
+    pub desc: Option<&'static str>,
+}
+
+#[cfg(feature = "rt")]
+impl core::fmt::Display for AssumptionViolated {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.desc {
+            Some(desc) => write!(f, "assumption violated: {}", desc),
+            None => f.write_str("assumption violated"),
+        }
+    }
+}
+
+#[cfg(feature = "rt")]
+impl AssumptionViolated {
+    /// Whether a panic payload is an [AssumptionViolated]
+    pub fn is_payload(payload: &(dyn core::any::Any + Send)) -> bool {
+        payload.is::<AssumptionViolated>()
+    }
+}
+
 #[cfg(feature = "rt")]
 mod rt_impls {
+    pub(super) use crate::rt::record_check;
+
     #[no_mangle]
-    pub extern "C" fn CVT_assume(c: bool) {
+    pub extern "C-unwind" fn CVT_assume(c: bool) {
+        record_check("assume", c);
         if !c {
-            panic!()
+            std::panic::panic_any(super::AssumptionViolated { desc: None })
         }
     }
 
     #[no_mangle]
-    pub extern "C" fn CVT_assert(c: bool) {
+    pub extern "C-unwind" fn CVT_assert(c: bool) {
+        record_check("assert", c);
         assert!(c);
     }
 
     #[no_mangle]
-    pub extern "C" fn CVT_satisfy(c: bool) {
+    pub extern "C-unwind" fn CVT_satisfy(c: bool) {
+        record_check("satisfy", c);
         assert!(c);
     }
 
     #[no_mangle]
-    pub extern "C" fn CVT_sanity(c: bool) {
-        CVT_satisfy(c)
+    pub extern "C-unwind" fn CVT_sanity(c: bool) {
+        record_check("sanity", c);
+        assert!(c);
     }
 }
 
@@ -55,6 +91,42 @@ pub fn cvlr_satisfy_checked(c: bool) {
     }
 }
 
+/// Like [cvlr_assert_checked], but `rt` failures panic with `desc`
+#[inline(always)]
+pub fn cvlr_assert_checked_with_desc(c: bool, desc: &'static str) {
+    #[cfg(feature = "rt")]
+    if !c {
+        rt_impls::record_check("assert", c);
+        panic!("assertion failed: {}", desc);
+    }
+    let _ = desc;
+    cvlr_assert_checked(c)
+}
+
+/// Like [cvlr_assume_checked], but `rt` failures carry `desc` in their payload
+#[inline(always)]
+pub fn cvlr_assume_checked_with_desc(c: bool, desc: &'static str) {
+    #[cfg(feature = "rt")]
+    if !c {
+        rt_impls::record_check("assume", c);
+        std::panic::panic_any(AssumptionViolated { desc: Some(desc) });
+    }
+    let _ = desc;
+    cvlr_assume_checked(c)
+}
+
+/// Like [cvlr_satisfy_checked], but `rt` failures panic with `desc`
+#[inline(always)]
+pub fn cvlr_satisfy_checked_with_desc(c: bool, desc: &'static str) {
+    #[cfg(feature = "rt")]
+    if !c {
+        rt_impls::record_check("satisfy", c);
+        panic!("satisfy failed: {}", desc);
+    }
+    let _ = desc;
+    cvlr_satisfy_checked(c)
+}
+
 #[inline(always)]
 pub fn cvlr_sanity_checked(c: bool) {
     unsafe {
@@ -64,27 +136,44 @@ pub fn cvlr_sanity_checked(c: bool) {
 
 #[macro_export]
 macro_rules! cvlr_assert {
-    ($cond: expr $(, $desc: literal)?) => {{
+    ($cond: expr) => {{
         let c_ = $cond;
         $crate::add_loc!();
         $crate::cvlr_assert_checked(c_);
     }};
+    ($cond: expr, $desc: literal) => {{
+        let c_ = $cond;
+        $crate::add_loc!();
+        $crate::log::add_desc($desc);
+        $crate::cvlr_assert_checked_with_desc(c_, $desc);
+    }};
 }
 
 #[macro_export]
 macro_rules! cvlr_assume {
-    ($cond: expr $(, $desc: literal)?) => {
+    ($cond: expr) => {
         $crate::cvlr_assume_checked($cond)
     };
+    ($cond: expr, $desc: literal) => {{
+        let c_ = $cond;
+        $crate::log::add_desc($desc);
+        $crate::cvlr_assume_checked_with_desc(c_, $desc);
+    }};
 }
 
 #[macro_export]
 macro_rules! cvlr_satisfy {
-    ($cond: expr $(, $desc: literal)?) => {{
+    ($cond: expr) => {{
         let c_ = $cond;
         $crate::add_loc!();
         $crate::cvlr_satisfy_checked(c_);
     }};
+    ($cond: expr, $desc: literal) => {{
+        let c_ = $cond;
+        $crate::add_loc!();
+        $crate::log::add_desc($desc);
+        $crate::cvlr_satisfy_checked_with_desc(c_, $desc);
+    }};
 }
 
 #[cfg(feature = "vacuity")]
@@ -1,11 +1,28 @@
+//! Assertions and assumptions
+//!
+//! The `rt` feature replaces the prover intrinsics by run-time checks. With
+//! `cvlr-log/rt` in the same build, as with `cvlr/rt`, checks are also
+//! recorded in the calltrace of `cvlr-log`, with their locations and
+//! descriptions.
 #![no_std]
 
+#[cfg(feature = "rt")]
+extern crate std;
+
 pub mod asserts;
 mod core;
 pub mod log;
+#[cfg(feature = "rt")]
+mod rt;
 
 pub use core::*;
 
+#[cfg(feature = "rt")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::rt::{set_calltrace, Calltrace};
+}
+
 #[deprecated = "Replaced by cvlr"]
 pub mod cvt {
     pub use crate::cvlr_assert as cvt_assert;
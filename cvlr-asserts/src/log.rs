@@ -1,11 +1,47 @@
+#[cfg(not(feature = "rt"))]
 mod rt_decls {
     extern "C" {
         #![allow(improper_ctypes)]
         // duplicated to avoid cvlr-assert depend on any other cvlr crate
         pub fn CVT_calltrace_attach_location(file: &str, line: u64);
+        pub fn CVT_calltrace_print_tag(tag: &str);
+        pub fn CVT_calltrace_scope_start(name: &str);
+        pub fn CVT_calltrace_scope_end(name: &str);
     }
 }
 
+// -- under rt, the calltrace installed by cvlr-log, if any
+#[cfg(feature = "rt")]
+#[allow(non_snake_case)]
+mod rt_decls {
+    use crate::rt::calltrace;
+
+    pub unsafe fn CVT_calltrace_attach_location(file: &str, line: u64) {
+        if let Some(c) = calltrace() {
+            (c.attach_location)(file, line);
+        }
+    }
+    pub unsafe fn CVT_calltrace_print_tag(tag: &str) {
+        if let Some(c) = calltrace() {
+            (c.print_tag)(tag);
+        }
+    }
+    pub unsafe fn CVT_calltrace_scope_start(name: &str) {
+        if let Some(c) = calltrace() {
+            (c.scope_start)(name);
+        }
+    }
+    pub unsafe fn CVT_calltrace_scope_end(name: &str) {
+        if let Some(c) = calltrace() {
+            (c.scope_end)(name);
+        }
+    }
+}
+
+/// Name of the calltrace scope that holds the description of the next assert,
+/// assume, or satisfy
+pub const DESC_SCOPE: &str = "desc";
+
 #[inline(always)]
 pub fn add_loc(file: &str, line: u32) {
     unsafe {
@@ -13,6 +49,18 @@ pub fn add_loc(file: &str, line: u32) {
     }
 }
 
+/// Attach a description to the next assert, assume, or satisfy in the calltrace
+///
+/// The description is printed as a tag in a [DESC_SCOPE] scope.
+#[inline(always)]
+pub fn add_desc(desc: &str) {
+    unsafe {
+        rt_decls::CVT_calltrace_scope_start(DESC_SCOPE);
+        rt_decls::CVT_calltrace_print_tag(desc);
+        rt_decls::CVT_calltrace_scope_end(DESC_SCOPE);
+    }
+}
+
 #[cfg(not(feature = "no-loc"))]
 #[macro_export]
 macro_rules! cvlr_asserts_core_file {
@@ -0,0 +1,38 @@
+//! Calltrace of `rt` checks
+//!
+//! The calltrace recorder lives in cvlr-log, which cannot be a dependency
+//! since it depends on cvlr-asserts through cvlr-mathint. Instead, cvlr-log
+//! installs its recorder here when it is first used. Until then, locations,
+//! descriptions, and check outcomes are not recorded, so that cvlr-asserts
+//! also runs without `cvlr-log/rt`.
+
+use std::sync::OnceLock;
+
+/// The calltrace functions used by cvlr-asserts
+pub struct Calltrace {
+    pub attach_location: fn(file: &str, line: u64),
+    pub print_tag: fn(tag: &str),
+    pub scope_start: fn(name: &str),
+    pub scope_end: fn(name: &str),
+    /// Outcome of an assert, assume, satisfy, or sanity check
+    pub record_check: fn(kind: &str, holds: bool),
+}
+
+static CALLTRACE: OnceLock<Calltrace> = OnceLock::new();
+
+/// Installs the calltrace; only the first call has an effect
+pub fn set_calltrace(calltrace: Calltrace) {
+    let _ = CALLTRACE.set(calltrace);
+}
+
+#[inline(always)]
+pub(crate) fn calltrace() -> Option<&'static Calltrace> {
+    CALLTRACE.get()
+}
+
+#[inline(always)]
+pub(crate) fn record_check(kind: &str, holds: bool) {
+    if let Some(c) = calltrace() {
+        (c.record_check)(kind, holds);
+    }
+}
@@ -13,11 +13,13 @@ fn main() {
     {
         let c_ = false;
         ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
-        ::cvlr_asserts::cvlr_assert_checked(c_);
+        ::cvlr_asserts::log::add_desc("this should fail");
+        ::cvlr_asserts::cvlr_assert_checked_with_desc(c_, "this should fail");
     };
     {
         let c_ = x > 0;
         ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
-        ::cvlr_asserts::cvlr_assert_checked(c_);
+        ::cvlr_asserts::log::add_desc("x must be positive");
+        ::cvlr_asserts::cvlr_assert_checked_with_desc(c_, "x must be positive");
     };
 }
@@ -67,7 +67,8 @@ fn main() {
         {
             let c_ = __cvlr_lhs <= __cvlr_rhs;
             ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
-            ::cvlr_asserts::cvlr_assert_checked(c_);
+            ::cvlr_asserts::log::add_desc("x must be <= y");
+            ::cvlr_asserts::cvlr_assert_checked_with_desc(c_, "x must be <= y");
         };
     };
 }
@@ -39,7 +39,11 @@ fn main() {
         {
             let c_ = __cvlr_lhs != __cvlr_rhs;
             ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
-            ::cvlr_asserts::cvlr_assert_checked(c_);
+            ::cvlr_asserts::log::add_desc("a and b must not be equal");
+            ::cvlr_asserts::cvlr_assert_checked_with_desc(
+                c_,
+                "a and b must not be equal",
+            );
         };
     };
 }
@@ -2,5 +2,9 @@ use cvlr_asserts::cvlr_assume;
 fn main() {
     ::cvlr_asserts::cvlr_assume_checked(true);
     ::cvlr_asserts::cvlr_assume_checked(x > 0);
-    ::cvlr_asserts::cvlr_assume_checked(y < 100);
+    {
+        let c_ = y < 100;
+        ::cvlr_asserts::log::add_desc("y must be less than 100");
+        ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "y must be less than 100");
+    };
 }
@@ -28,6 +28,10 @@ fn main() {
         ::cvlr_log::cvlr_log("a", &(__cvlr_lhs));
         ::cvlr_log::cvlr_log("b", &(__cvlr_rhs));
         cvlr::log::log_scope_end("assume");
-        ::cvlr_asserts::cvlr_assume_checked(__cvlr_lhs == __cvlr_rhs);
+        {
+            let c_ = __cvlr_lhs == __cvlr_rhs;
+            ::cvlr_asserts::log::add_desc("assume a equals b");
+            ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "assume a equals b");
+        };
     };
 }
@@ -13,6 +13,7 @@ fn main() {
     {
         let c_ = z > 0;
         ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
-        ::cvlr_asserts::cvlr_satisfy_checked(c_);
+        ::cvlr_asserts::log::add_desc("z must be positive");
+        ::cvlr_asserts::cvlr_satisfy_checked_with_desc(c_, "z must be positive");
     };
 }
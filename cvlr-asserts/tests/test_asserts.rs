@@ -39,12 +39,12 @@ fn test_assert_ne_pass() {
     cvlr_assert_ne!("hello", "world");
 }
 
-// #[cfg(feature = "rt")]
-// #[test]
-// #[should_panic]
-// fn test_assert_ne_fail() {
-//     cvlr_assert_ne!(1, 1);
-// }
+#[cfg(feature = "rt")]
+#[test]
+#[should_panic]
+fn test_assert_ne_fail() {
+    cvlr_assert_ne!(1, 1);
+}
 
 #[cfg(feature = "rt")]
 #[test]
@@ -183,3 +183,35 @@ fn test_assert_eq_different_numeric_types() {
     cvlr_assert_eq!(1i32, 1i32);
     cvlr_assert_eq!(1i64, 1i64);
 }
+
+// Test that descriptions are reported in rt failures
+#[cfg(feature = "rt")]
+#[test]
+#[should_panic(expected = "assertion failed: x must be positive")]
+fn test_assert_desc_in_panic() {
+    let x = 0;
+    cvlr_assert!(x > 0, "x must be positive");
+}
+
+#[cfg(feature = "rt")]
+#[test]
+#[should_panic(expected = "assertion failed: values should be equal")]
+fn test_assert_eq_desc_in_panic() {
+    cvlr_assert_eq!(1, 2, "values should be equal");
+}
+
+#[cfg(feature = "rt")]
+#[test]
+#[should_panic(expected = "satisfy failed: reachable")]
+fn test_satisfy_desc_in_panic() {
+    cvlr_satisfy!(false, "reachable");
+}
+
+#[cfg(feature = "rt")]
+#[test]
+fn test_assume_desc_in_payload() {
+    let payload = std::panic::catch_unwind(|| cvlr_assume_lt!(2, 1, "ordered")).unwrap_err();
+    let violated = payload.downcast_ref::<AssumptionViolated>().unwrap();
+    assert_eq!(violated.desc, Some("ordered"));
+    assert_eq!(violated.to_string(), "assumption violated: ordered");
+}
@@ -1,6 +1,6 @@
 use cvlr_log::{CvlrLog, CvlrLogger};
 use cvlr_mathint::NativeInt;
-use cvlr_nondet::{nondet, Nondet};
+use cvlr_nondet::{nondet, Nondet, NondetRange};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub struct NativeDecimal<const D: u32> {
@@ -53,6 +53,18 @@ impl<const D: u32> Nondet for NativeDecimal<D> {
     }
 }
 
+impl<const D: u32> NondetRange for NativeDecimal<D> {
+    fn nondet_in<R: core::ops::RangeBounds<Self>>(range: R) -> Self {
+        let val = NativeInt::nondet_in_bounds(
+            range.start_bound().map(|b| b.val),
+            range.end_bound().map(|b| b.val),
+        );
+        let v = Self::new(val);
+        cvlr_log::cvlr_log(cvlr_nondet::NONDET_IN_TAG, &v);
+        v
+    }
+}
+
 impl<const D: u32> core::ops::Add<NativeDecimal<D>> for NativeDecimal<D> {
     type Output = Self;
     fn add(self, other: NativeDecimal<D>) -> Self::Output {
@@ -175,6 +187,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn test_comparison_operations() {
         let a: NativeDecimal<2> = NativeDecimal::new(5.into());
         let b: NativeDecimal<2> = NativeDecimal::new(10.into());
@@ -222,6 +235,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_clone_and_copy() {
         let a: NativeDecimal<2> = NativeDecimal::new(42.into());
         let b = a; // Copy
@@ -232,6 +246,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::explicit_auto_deref)]
     fn test_deref() {
         let decimal: NativeDecimal<2> = NativeDecimal::new(100.into());
         let native_int: &NativeInt = &*decimal;
@@ -382,4 +397,15 @@ mod tests {
         assert_eq!(decimal_u16.as_int(), expected);
         assert_eq!(decimal_u8.as_int(), expected);
     }
+
+    #[test]
+    fn test_nondet_in() {
+        let lo: NativeDecimal<2> = NativeDecimal::as_decimal(100u64);
+        let hi: NativeDecimal<2> = NativeDecimal::as_decimal(200u64);
+        let d: NativeDecimal<2> = cvlr_nondet::nondet_in(lo..=hi);
+        assert!(d >= lo && d <= hi);
+
+        let d = NativeDecimal::<2>::nondet_in(hi..);
+        assert!(d >= hi);
+    }
 }
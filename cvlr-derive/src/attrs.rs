@@ -0,0 +1,238 @@
+//! Parsing of `#[cvlr(...)]` attributes shared by the derives
+
+use {
+    proc_macro2::{Group, Ident, Span, TokenStream, TokenTree},
+    quote::quote,
+    syn::{
+        parse::Parser, parse_quote, punctuated::Punctuated, Attribute, DeriveInput, Expr, Field,
+        GenericParam, Generics, LitStr, Path, Token, WherePredicate,
+    },
+};
+
+/// An assumption given as a string, such as `"self.a <= self.b"`
+pub struct Assume {
+    lit: LitStr,
+    cond: TokenStream,
+}
+
+impl Assume {
+    fn parse(lit: LitStr) -> syn::Result<Self> {
+        let cond: Expr = lit.parse()?;
+        Ok(Self {
+            cond: replace_self(quote!(#cond)),
+            lit,
+        })
+    }
+
+    /// Assumes the condition, with `self` referring to `this`
+    pub fn to_assume(&self, this: &Ident) -> TokenStream {
+        let lit = &self.lit;
+        let cond = &self.cond;
+        quote! {
+            {
+                let __cvlr_self = &#this;
+                ::cvlr::asserts::cvlr_assume!(#cond, #lit);
+            }
+        }
+    }
+}
+
+/// Replaces `self` by `__cvlr_self`, since `nondet()` has no receiver
+fn replace_self(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == "self" => {
+                TokenTree::Ident(Ident::new("__cvlr_self", ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut g = Group::new(group.delimiter(), replace_self(group.stream()));
+                g.set_span(group.span());
+                TokenTree::Group(g)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+/// A derive that reads the `#[cvlr(...)]` attributes of the item
+#[derive(Clone, Copy)]
+pub enum Derive {
+    Nondet,
+    Log,
+    Frame,
+}
+
+/// Where-predicates given by `#[cvlr(bound = "...")]`, for every derive, or by
+/// `#[cvlr(bound(nondet = "...", log = "...", frame = "..."))]`, for some
+#[derive(Default)]
+pub struct Bounds {
+    all: Option<Vec<WherePredicate>>,
+    nondet: Option<Vec<WherePredicate>>,
+    log: Option<Vec<WherePredicate>>,
+    frame: Option<Vec<WherePredicate>>,
+}
+
+impl Bounds {
+    /// Bounds of `derive`, if they are overridden
+    fn of(&self, derive: Derive) -> Option<&Vec<WherePredicate>> {
+        let own = match derive {
+            Derive::Nondet => &self.nondet,
+            Derive::Log => &self.log,
+            Derive::Frame => &self.frame,
+        };
+        own.as_ref().or(self.all.as_ref())
+    }
+}
+
+fn parse_bound(lit: LitStr) -> syn::Result<Vec<WherePredicate>> {
+    let preds = Punctuated::<WherePredicate, Token![,]>::parse_terminated
+        .parse_str(&lit.value())
+        .map_err(|e| syn::Error::new(lit.span(), e))?;
+    Ok(preds.into_iter().collect())
+}
+
+/// Attributes on the item
+#[derive(Default)]
+pub struct TypeAttrs {
+    /// Bounds given by `#[cvlr(bound = "...")]` or `#[cvlr(bound(...))]`
+    pub bound: Bounds,
+    /// Assumptions given by `#[cvlr(assume = "...")]`
+    pub assumes: Vec<Assume>,
+}
+
+impl TypeAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("cvlr")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bound") {
+                    if meta.input.peek(Token![=]) {
+                        res.bound.all = Some(parse_bound(meta.value()?.parse()?)?);
+                        return Ok(());
+                    }
+                    meta.parse_nested_meta(|meta| {
+                        let slot = if meta.path.is_ident("nondet") {
+                            &mut res.bound.nondet
+                        } else if meta.path.is_ident("log") {
+                            &mut res.bound.log
+                        } else if meta.path.is_ident("frame") {
+                            &mut res.bound.frame
+                        } else {
+                            return Err(meta
+                                .error("unsupported bound, expected `nondet`, `log`, or `frame`"));
+                        };
+                        *slot = Some(parse_bound(meta.value()?.parse()?)?);
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("assume") {
+                    res.assumes.push(Assume::parse(meta.value()?.parse()?)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported cvlr attribute, expected `bound` or `assume`"))
+                }
+            })?;
+        }
+        Ok(res)
+    }
+}
+
+/// How a field of a derived `Nondet` value is created
+pub enum FieldInit {
+    /// `nondet()`
+    Nondet,
+    /// `#[cvlr(range = lo..hi)]`: `nondet_in(lo..hi)`
+    Range(Expr),
+    /// `#[cvlr(with = path)]`: `path()`
+    With(Path),
+    /// `#[cvlr(default)]`: `Default::default()`
+    Default,
+}
+
+/// Attributes on a field
+pub struct FieldAttrs {
+    pub init: FieldInit,
+    /// Assumptions given by `#[cvlr(assume = "...")]`
+    pub assumes: Vec<Assume>,
+}
+
+impl FieldAttrs {
+    pub fn parse(field: &Field) -> syn::Result<Self> {
+        let mut init = None;
+        let mut assumes = Vec::new();
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("cvlr")) {
+            attr.parse_nested_meta(|meta| {
+                let new_init = if meta.path.is_ident("range") {
+                    FieldInit::Range(meta.value()?.parse()?)
+                } else if meta.path.is_ident("with") {
+                    FieldInit::With(meta.value()?.parse()?)
+                } else if meta.path.is_ident("default") {
+                    FieldInit::Default
+                } else if meta.path.is_ident("assume") {
+                    assumes.push(Assume::parse(meta.value()?.parse()?)?);
+                    return Ok(());
+                } else {
+                    return Err(meta.error(
+                        "unsupported cvlr attribute, expected `range`, `with`, `default`, or `assume`",
+                    ));
+                };
+                if init.is_some() {
+                    return Err(
+                        meta.error("at most one of `range`, `with`, and `default` is allowed")
+                    );
+                }
+                init = Some(new_init);
+                Ok(())
+            })?;
+        }
+        Ok(Self {
+            init: init.unwrap_or(FieldInit::Nondet),
+            assumes,
+        })
+    }
+
+    /// Expression that creates the field
+    pub fn init_expr(&self) -> TokenStream {
+        match &self.init {
+            FieldInit::Nondet => quote! { ::cvlr::nondet::nondet() },
+            FieldInit::Range(range) => quote! { ::cvlr::nondet::nondet_in(#range) },
+            FieldInit::With(path) => quote! { #path() },
+            FieldInit::Default => quote! { ::core::default::Default::default() },
+        }
+    }
+}
+
+/// Generics of the derived impl
+///
+/// Every type parameter is bounded by `bound`, unless the item overrides the
+/// bounds of `derive` with `#[cvlr(bound = "...")]` or `#[cvlr(bound(...))]`.
+pub fn with_bounds(
+    input: &DeriveInput,
+    attrs: &TypeAttrs,
+    derive: Derive,
+    bound: TokenStream,
+) -> Generics {
+    let mut generics = input.generics.clone();
+    let preds = match attrs.bound.of(derive) {
+        Some(preds) => preds.clone(),
+        None => input
+            .generics
+            .params
+            .iter()
+            .filter_map(|p| match p {
+                GenericParam::Type(t) => {
+                    let ident = &t.ident;
+                    Some(parse_quote!(#ident: #bound))
+                }
+                _ => None,
+            })
+            .collect(),
+    };
+    generics.make_where_clause().predicates.extend(preds);
+    generics
+}
+
+/// Name of the value under construction in a derived `nondet()`
+pub fn self_ident() -> Ident {
+    Ident::new("__cvlr_nondet", Span::call_site())
+}
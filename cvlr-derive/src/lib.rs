@@ -1,72 +1,107 @@
+mod attrs;
+
 use {
+    attrs::{self_ident, with_bounds, Derive, FieldAttrs, TypeAttrs},
     proc_macro::TokenStream,
     proc_macro2::Span,
     quote::quote,
     syn::{
         parse_macro_input,
         Data::{Enum, Struct, Union},
-        DeriveInput,
+        DeriveInput, Field,
         Fields::{self, Named, Unnamed},
         FieldsNamed, FieldsUnnamed, Ident, Index, Variant,
     },
 };
 
-fn of_named_fields(n: &Ident, named_fields: &FieldsNamed) -> proc_macro2::TokenStream {
-    let initialize = named_fields.named.iter().map(|f| {
-        let name = f.ident.as_ref().unwrap();
-        quote! {
-            #name: ::cvlr::nondet::nondet(),
-        }
-    });
-
-    quote! {
-        #n {
-            #( #initialize )*
-        }
+/// Initializers of `fields` and the assumptions on them
+fn of_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
+) -> syn::Result<(Vec<proc_macro2::TokenStream>, Vec<attrs::Assume>)> {
+    let mut inits = Vec::new();
+    let mut assumes = Vec::new();
+    for f in fields {
+        let attrs = FieldAttrs::parse(f)?;
+        let init = attrs.init_expr();
+        inits.push(match &f.ident {
+            Some(name) => quote! { #name: #init, },
+            None => quote! { #init, },
+        });
+        assumes.extend(attrs.assumes);
     }
+    Ok((inits, assumes))
 }
 
-fn of_unnamed_fields(n: &Ident, unnamed: &FieldsUnnamed) -> proc_macro2::TokenStream {
-    let initialize = unnamed.unnamed.iter().map(|_| {
-        quote! { ::cvlr::nondet::nondet(), }
-    });
+fn of_named_fields(
+    n: &Ident,
+    named_fields: &FieldsNamed,
+) -> syn::Result<(proc_macro2::TokenStream, Vec<attrs::Assume>)> {
+    let (initialize, assumes) = of_fields(named_fields.named.iter())?;
+    Ok((
+        quote! {
+            #n {
+                #( #initialize )*
+            }
+        },
+        assumes,
+    ))
+}
 
-    quote! {
-        #n (
-            #( #initialize )*
-        )
-    }
+fn of_unnamed_fields(
+    n: &Ident,
+    unnamed: &FieldsUnnamed,
+) -> syn::Result<(proc_macro2::TokenStream, Vec<attrs::Assume>)> {
+    let (initialize, assumes) = of_fields(unnamed.unnamed.iter())?;
+    Ok((
+        quote! {
+            #n (
+                #( #initialize )*
+            )
+        },
+        assumes,
+    ))
 }
 
-fn of_enum_variant(variant: &Variant, enum_name: &Ident) -> proc_macro2::TokenStream {
+fn of_enum_variant(variant: &Variant, enum_name: &Ident) -> syn::Result<proc_macro2::TokenStream> {
     let variant_name = &variant.ident;
-    match &variant.fields {
+    let (initialize, assumes) = of_fields(variant.fields.iter())?;
+    if !assumes.is_empty() {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "`assume` is not supported on fields of enum variants, use it on the enum instead",
+        ));
+    }
+    Ok(match &variant.fields {
         Fields::Unit => quote! {
             #enum_name::#variant_name
         },
-        Fields::Unnamed(unnamed) => {
-            let initialize = unnamed.unnamed.iter().map(|_| {
-                quote! { ::cvlr::nondet::nondet(), }
-            });
-            quote! {
-                #enum_name::#variant_name(
-                    #( #initialize )*
-                )
-            }
-        }
-        Fields::Named(named) => {
-            let initialize = named.named.iter().map(|f| {
-                let field_name = f.ident.as_ref().unwrap();
-                quote! {
-                    #field_name: ::cvlr::nondet::nondet(),
-                }
-            });
-            quote! {
-                #enum_name::#variant_name {
-                    #( #initialize )*
-                }
+        Fields::Unnamed(_) => quote! {
+            #enum_name::#variant_name(
+                #( #initialize )*
+            )
+        },
+        Fields::Named(_) => quote! {
+            #enum_name::#variant_name {
+                #( #initialize )*
             }
-        }
+        },
+    })
+}
+
+/// Body of `nondet()` that creates the value with `init` and then applies `assumes`
+fn with_assumes(
+    init: proc_macro2::TokenStream,
+    assumes: &[attrs::Assume],
+) -> proc_macro2::TokenStream {
+    if assumes.is_empty() {
+        return init;
+    }
+    let this = self_ident();
+    let assumes = assumes.iter().map(|a| a.to_assume(&this));
+    quote! {
+        let #this = #init;
+        #( #assumes )*
+        #this
     }
 }
 
@@ -97,25 +132,91 @@ fn of_enum_variant(variant: &Variant, enum_name: &Ident) -> proc_macro2::TokenSt
 /// let p = Point::nondet();
 /// let e = MyEnum::nondet();
 /// ```
-#[proc_macro_derive(Nondet)]
+///
+/// # Generics
+///
+/// Lifetime, type, and const parameters are carried over to the impl, and
+/// every type parameter `T` gets a `T: Nondet` bound. The bounds are replaced
+/// with `#[cvlr(bound = "...")]`:
+///
+/// ```ignore
+/// #[derive(Nondet)]
+/// #[cvlr(bound = "M::Price: Nondet")]
+/// struct Quote<M: Market> {
+///     price: M::Price,
+/// }
+/// ```
+///
+/// `bound = "..."` applies to every derive of the item. When several derives
+/// need different bounds, `#[cvlr(bound(nondet = "...", log = "...", frame =
+/// "..."))]` gives them per derive:
+///
+/// ```ignore
+/// #[derive(Nondet, CvlrLog)]
+/// #[cvlr(bound(nondet = "M::Price: Nondet", log = "M::Price: CvlrLog"))]
+/// struct Quote<M: Market> {
+///     price: M::Price,
+/// }
+/// ```
+///
+/// # Constraints
+///
+/// Fields are created with `nondet()` unless one of the following attributes
+/// is given:
+///
+/// - `#[cvlr(range = 1..=100)]` creates the field with `nondet_in(1..=100)`
+/// - `#[cvlr(with = path::to::fn)]` creates the field by calling `path::to::fn()`
+/// - `#[cvlr(default)]` creates the field with `Default::default()`
+///
+/// `#[cvlr(assume = "...")]`, on a field of a struct or on the type, assumes a
+/// condition on the created value, in which `self` is the value. Since the
+/// constraints are applied by `nondet()`, `nondet_with` adds its condition on
+/// top of them.
+///
+/// ```ignore
+/// #[derive(Nondet)]
+/// #[cvlr(assume = "self.lo <= self.hi")]
+/// struct Interval {
+///     #[cvlr(range = 0..1000)]
+///     lo: u64,
+///     #[cvlr(assume = "self.hi > 0")]
+///     hi: u64,
+///     #[cvlr(default)]
+///     hits: u64,
+/// }
+/// ```
+#[proc_macro_derive(Nondet, attributes(cvlr))]
 pub fn derive_nondet(item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as DeriveInput);
-    let name = input.ident;
-    match input.data {
+    match derive_nondet_impl(parse_macro_input!(item as DeriveInput)) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn derive_nondet_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let type_attrs = TypeAttrs::parse(&input.attrs)?;
+    let generics = with_bounds(
+        &input,
+        &type_attrs,
+        Derive::Nondet,
+        quote!(::cvlr::nondet::Nondet),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+    let (init, field_assumes) = match &input.data {
         Enum(data_enum) => {
             let variants = &data_enum.variants;
             let variant_count = variants.len();
 
             if variant_count == 0 {
-                return quote! {
+                return Ok(quote! {
                     compile_error!("Enum must have at least one variant");
-                }
-                .into();
+                });
             }
 
             let mut match_arms = Vec::new();
             for (index, variant) in variants.iter().enumerate() {
-                let variant_expr = of_enum_variant(variant, &name);
+                let variant_expr = of_enum_variant(variant, name)?;
                 if index == variant_count - 1 {
                     // Last variant is catch-all
                     match_arms.push(quote! {
@@ -129,57 +230,37 @@ pub fn derive_nondet(item: TokenStream) -> TokenStream {
                 }
             }
 
-            quote! {
-                impl ::cvlr::nondet::Nondet for #name {
-                    fn nondet() -> #name {
-                        match ::cvlr::nondet::nondet::<u64>() {
-                            #( #match_arms )*
-                        }
-                    }
+            let init = quote! {
+                match ::cvlr::nondet::nondet::<u64>() {
+                    #( #match_arms )*
                 }
-            }
-            .into()
+            };
+            (init, Vec::new())
         }
 
         Union(_) => {
             todo!("Union not supported yet")
         }
 
-        Struct(ds) => match ds.fields {
-            Fields::Unit => quote! {
-                impl ::cvlr::nondet::Nondet for #name {
-                    fn nondet() -> #name {
-                        #name
-                    }
-                }
-            }
-            .into(),
-
-            Named(named) => {
-                let init = of_named_fields(&name, &named);
-                quote! {
-                    impl ::cvlr::nondet::Nondet for #name {
-                        fn nondet() -> #name {
-                            #init
-                        }
-                    }
-                }
-                .into()
-            }
+        Struct(ds) => match &ds.fields {
+            Fields::Unit => (quote! { #name }, Vec::new()),
+            Named(named) => of_named_fields(name, named)?,
+            Unnamed(fields) => of_unnamed_fields(name, fields)?,
+        },
+    };
 
-            Unnamed(fields) => {
-                let init = of_unnamed_fields(&name, &fields);
-                quote! {
-                    impl ::cvlr::nondet::Nondet for #name {
-                        fn nondet() -> #name {
-                            #init
-                        }
-                    }
-                }
-                .into()
+    let assumes: Vec<_> = field_assumes
+        .into_iter()
+        .chain(type_attrs.assumes)
+        .collect();
+    let body = with_assumes(init, &assumes);
+    Ok(quote! {
+        impl #impl_generics ::cvlr::nondet::Nondet for #name #ty_generics #where_clause {
+            fn nondet() -> #name #ty_generics {
+                #body
             }
-        },
-    }
+        }
+    })
 }
 
 /// Derive macro for implementing the `CvlrLog` trait
@@ -225,9 +306,26 @@ pub fn derive_nondet(item: TokenStream) -> TokenStream {
 /// let e = MyEnum::Variant2(42);
 /// e.log("enum", &mut logger);
 /// ```
-#[proc_macro_derive(CvlrLog)]
+///
+/// # Generics
+///
+/// As for `#[derive(Nondet)]`, generic parameters are carried over to the
+/// impl, with a `T: CvlrLog` bound for every type parameter `T` unless
+/// `#[cvlr(bound = "...")]` or `#[cvlr(bound(log = "..."))]` is given.
+#[proc_macro_derive(CvlrLog, attributes(cvlr))]
 pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
+    let type_attrs = match TypeAttrs::parse(&input.attrs) {
+        Ok(attrs) => attrs,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let generics = with_bounds(
+        &input,
+        &type_attrs,
+        Derive::Log,
+        quote!(::cvlr::log::CvlrLog),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let name = input.ident;
 
     match input.data {
@@ -289,7 +387,7 @@ pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
             }).collect();
 
             quote! {
-                impl ::cvlr::log::CvlrLog for #name {
+                impl #impl_generics ::cvlr::log::CvlrLog for #name #ty_generics #where_clause {
                     #[inline(always)]
                     fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
                         match self {
@@ -309,7 +407,7 @@ pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
         Struct(ds) => {
             match ds.fields {
                 Fields::Unit => quote! {
-                    impl ::cvlr::log::CvlrLog for #name {
+                    impl #impl_generics ::cvlr::log::CvlrLog for #name #ty_generics #where_clause {
                         #[inline(always)]
                         fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
                             logger.log_scope_start(tag);
@@ -329,7 +427,7 @@ pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
                 }).collect();
 
                     quote! {
-                        impl ::cvlr::log::CvlrLog for #name {
+                        impl #impl_generics ::cvlr::log::CvlrLog for #name #ty_generics #where_clause {
                             #[inline(always)]
                             fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
                                 logger.log_scope_start(tag);
@@ -351,7 +449,7 @@ pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
                 }).collect();
 
                     quote! {
-                        impl ::cvlr::log::CvlrLog for #name {
+                        impl #impl_generics ::cvlr::log::CvlrLog for #name #ty_generics #where_clause {
                             #[inline(always)]
                             fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
                                 logger.log_scope_start(tag);
@@ -366,3 +464,134 @@ pub fn derive_cvlr_log(item: TokenStream) -> TokenStream {
         }
     }
 }
+
+/// Derive macro for implementing the `CvlrFrame` trait
+///
+/// The generated implementation compares every field of a context between two
+/// states with `cvlr_assert_eq!` (or `cvlr_assume_eq!`), skipping the fields
+/// that are allowed to change. Each compared pair is logged, so the calltrace
+/// of a failed frame condition shows the field that was mutated.
+///
+/// Supports structs with named fields and tuple structs, whose fields are
+/// named `"0"`, `"1"`, etc. Every field must implement `PartialEq` and
+/// `CvlrLog`, and a context has at most 64 fields.
+///
+/// # Example
+///
+/// ```ignore
+/// use cvlr::prelude::*;
+///
+/// #[derive(CvlrFrame)]
+/// struct Account {
+///     owner: u64,
+///     balance: u64,
+/// }
+///
+/// // -- after a deposit, only the balance may change
+/// cvlr_frame!(Account, except: [balance]).assert_with_states(&post, &pre);
+/// ```
+///
+/// # Generics
+///
+/// Every type parameter `T` is bounded by `T: PartialEq + CvlrLog`, unless
+/// `#[cvlr(bound = "...")]` or `#[cvlr(bound(frame = "..."))]` is given.
+#[proc_macro_derive(CvlrFrame, attributes(cvlr))]
+pub fn derive_cvlr_frame(item: TokenStream) -> TokenStream {
+    match derive_cvlr_frame_impl(parse_macro_input!(item as DeriveInput)) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn derive_cvlr_frame_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let type_attrs = TypeAttrs::parse(&input.attrs)?;
+    let generics = with_bounds(
+        &input,
+        &type_attrs,
+        Derive::Frame,
+        quote!(::core::cmp::PartialEq + ::cvlr::log::CvlrLog),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+
+    let members: Vec<(syn::Member, String)> = match &input.data {
+        Struct(ds) => match &ds.fields {
+            Fields::Unit => Vec::new(),
+            Named(named) => named
+                .named
+                .iter()
+                .map(|f| {
+                    let ident = f.ident.clone().unwrap();
+                    let name = ident.to_string();
+                    (syn::Member::Named(ident), name)
+                })
+                .collect(),
+            Unnamed(unnamed) => (0..unnamed.unnamed.len())
+                .map(|i| (syn::Member::Unnamed(Index::from(i)), i.to_string()))
+                .collect(),
+        },
+        Enum(_) | Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "CvlrFrame derive is only supported for structs",
+            ))
+        }
+    };
+
+    // -- fields that may change are given as a mask, one bit per field
+    if members.len() > 64 {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "CvlrFrame derive supports at most 64 fields",
+        ));
+    }
+    let field_names: Vec<_> = members.iter().map(|(_, n)| n).collect();
+    let evals = members.iter().enumerate().map(|(i, (m, _))| {
+        quote! {
+            (except & (1u64 << #i) != 0 || post.#m == old.#m)
+        }
+    });
+    let asserts = members.iter().enumerate().map(|(i, (m, n))| {
+        let desc = format!("{} unchanged", n);
+        quote! {
+            if except & (1u64 << #i) == 0 {
+                ::cvlr::asserts::cvlr_assert_eq!(&post.#m, &old.#m, #desc);
+            }
+        }
+    });
+    let assumes = members.iter().enumerate().map(|(i, (m, n))| {
+        let desc = format!("{} unchanged", n);
+        quote! {
+            if except & (1u64 << #i) == 0 {
+                ::cvlr::asserts::cvlr_assume_eq!(&post.#m, &old.#m, #desc);
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::cvlr::spec::CvlrFrame for #name #ty_generics #where_clause {
+            const FIELDS: &'static [&'static str] = &[#( #field_names ),*];
+
+            #[inline(always)]
+            #[allow(unused_variables)]
+            fn eval_frame(&self, old: &Self, except: u64) -> bool {
+                let post = self;
+                true #( && #evals )*
+            }
+
+            #[inline(always)]
+            #[allow(unused_variables)]
+            fn assert_frame(&self, old: &Self, except: u64) {
+                let post = self;
+                #( #asserts )*
+            }
+
+            #[inline(always)]
+            #[allow(unused_variables)]
+            fn assume_frame(&self, old: &Self, except: u64) {
+                let post = self;
+                #( #assumes )*
+            }
+        }
+    })
+}
@@ -0,0 +1,6 @@
+use cvlr_derive::CvlrFrame;
+enum Side {
+    Bid,
+    Ask,
+}
+fn main() {}
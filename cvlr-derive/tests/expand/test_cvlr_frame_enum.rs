@@ -0,0 +1,9 @@
+use cvlr_derive::CvlrFrame;
+
+#[derive(CvlrFrame)]
+enum Side {
+    Bid,
+    Ask,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use cvlr::spec::{cvlr_frame, CvlrFrame};
+struct Account {
+    owner: u64,
+    balance: u64,
+}
+impl CvlrFrame for Account {
+    const FIELDS: &'static [&'static str] = &["owner", "balance"];
+    fn eval_frame(&self, _old: &Self, _except: u64) -> bool {
+        true
+    }
+    fn assert_frame(&self, _old: &Self, _except: u64) {}
+    fn assume_frame(&self, _old: &Self, _except: u64) {}
+}
+fn main() {
+    let _ = {
+        let _ = |__cvlr_ctx: &Account| {
+            let _ = &__cvlr_ctx.balanse;
+        };
+        const __CVLR_FRAME: ::cvlr_spec::Frame<Account> = ::cvlr_spec::Frame::except(
+            &["balanse"],
+        );
+        __CVLR_FRAME
+    };
+}
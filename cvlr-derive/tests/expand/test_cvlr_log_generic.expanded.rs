@@ -0,0 +1,73 @@
+use cvlr::log::CvlrLog;
+use cvlr_derive::CvlrLog;
+struct Pool<'a, T> {
+    reserve: &'a T,
+    fee: u64,
+}
+impl<'a, T> ::cvlr::log::CvlrLog for Pool<'a, T>
+where
+    T: ::cvlr::log::CvlrLog,
+{
+    #[inline(always)]
+    fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
+        logger.log_scope_start(tag);
+        ::cvlr::log::cvlr_log_with("reserve", &self.reserve, logger);
+        ::cvlr::log::cvlr_log_with("fee", &self.fee, logger);
+        logger.log_scope_end(tag);
+    }
+}
+struct Scaled<const DECIMALS: u32>(u64);
+impl<const DECIMALS: u32> ::cvlr::log::CvlrLog for Scaled<DECIMALS> {
+    #[inline(always)]
+    fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
+        logger.log_scope_start(tag);
+        ::cvlr::log::cvlr_log_with("0", &self.0, logger);
+        logger.log_scope_end(tag);
+    }
+}
+enum Either<A, B> {
+    Left(A),
+    Right { value: B },
+}
+impl<A, B> ::cvlr::log::CvlrLog for Either<A, B>
+where
+    A: ::cvlr::log::CvlrLog,
+    B: ::cvlr::log::CvlrLog,
+{
+    #[inline(always)]
+    fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
+        match self {
+            Either::Left(ref field0) => {
+                logger.log_scope_start(tag);
+                logger.log_str(tag, "Left");
+                ::cvlr::log::cvlr_log_with("0", &field0, logger);
+                logger.log_scope_end(tag);
+            }
+            Either::Right { ref value } => {
+                logger.log_scope_start(tag);
+                logger.log_str(tag, "Right");
+                ::cvlr::log::cvlr_log_with("value", &value, logger);
+                logger.log_scope_end(tag);
+            }
+        }
+    }
+}
+pub trait Market {
+    type Price;
+}
+#[cvlr(bound = "M::Price: CvlrLog")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+impl<M: Market> ::cvlr::log::CvlrLog for Quote<M>
+where
+    M::Price: CvlrLog,
+{
+    #[inline(always)]
+    fn log(&self, tag: &str, logger: &mut ::cvlr::log::CvlrLogger) {
+        logger.log_scope_start(tag);
+        ::cvlr::log::cvlr_log_with("price", &self.price, logger);
+        logger.log_scope_end(tag);
+    }
+}
+fn main() {}
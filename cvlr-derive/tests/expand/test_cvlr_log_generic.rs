@@ -0,0 +1,29 @@
+use cvlr::log::CvlrLog;
+use cvlr_derive::CvlrLog;
+
+#[derive(CvlrLog)]
+struct Pool<'a, T> {
+    reserve: &'a T,
+    fee: u64,
+}
+
+#[derive(CvlrLog)]
+struct Scaled<const DECIMALS: u32>(u64);
+
+#[derive(CvlrLog)]
+enum Either<A, B> {
+    Left(A),
+    Right { value: B },
+}
+
+pub trait Market {
+    type Price;
+}
+
+#[derive(CvlrLog)]
+#[cvlr(bound = "M::Price: CvlrLog")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+
+fn main() {}
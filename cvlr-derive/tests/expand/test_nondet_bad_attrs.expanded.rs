@@ -0,0 +1,13 @@
+use cvlr_derive::Nondet;
+struct Conflicting {
+    #[cvlr(range = 0..10, default)]
+    x: u64,
+}
+struct Unknown {
+    #[cvlr(between = 0..10)]
+    x: u64,
+}
+enum VariantAssume {
+    A(#[cvlr(assume = "true")] u64),
+}
+fn main() {}
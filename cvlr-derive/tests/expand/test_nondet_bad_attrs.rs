@@ -0,0 +1,20 @@
+use cvlr_derive::Nondet;
+
+#[derive(Nondet)]
+struct Conflicting {
+    #[cvlr(range = 0..10, default)]
+    x: u64,
+}
+
+#[derive(Nondet)]
+struct Unknown {
+    #[cvlr(between = 0..10)]
+    x: u64,
+}
+
+#[derive(Nondet)]
+enum VariantAssume {
+    A(#[cvlr(assume = "true")] u64),
+}
+
+fn main() {}
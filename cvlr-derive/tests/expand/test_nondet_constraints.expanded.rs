@@ -0,0 +1,83 @@
+use cvlr::nondet::Nondet;
+use cvlr_derive::Nondet;
+fn even() -> u64 {
+    2
+}
+#[cvlr(assume = "self.lo <= self.hi")]
+struct Interval {
+    #[cvlr(range = 0..1000)]
+    lo: u64,
+    #[cvlr(assume = "self.hi > 0")]
+    hi: u64,
+    #[cvlr(default)]
+    hits: u64,
+    #[cvlr(with = even)]
+    step: u64,
+}
+impl ::cvlr::nondet::Nondet for Interval {
+    fn nondet() -> Interval {
+        let __cvlr_nondet = Interval {
+            lo: ::cvlr::nondet::nondet_in(0..1000),
+            hi: ::cvlr::nondet::nondet(),
+            hits: ::core::default::Default::default(),
+            step: even(),
+        };
+        {
+            let __cvlr_self = &__cvlr_nondet;
+            {
+                let c_ = __cvlr_self.hi > 0;
+                ::cvlr_asserts::log::add_desc("self.hi > 0");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "self.hi > 0");
+            };
+        }
+        {
+            let __cvlr_self = &__cvlr_nondet;
+            {
+                let c_ = __cvlr_self.lo <= __cvlr_self.hi;
+                ::cvlr_asserts::log::add_desc("self.lo <= self.hi");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "self.lo <= self.hi");
+            };
+        }
+        __cvlr_nondet
+    }
+}
+struct Percent(#[cvlr(range = 0..= 100)] u8);
+impl ::cvlr::nondet::Nondet for Percent {
+    fn nondet() -> Percent {
+        Percent(::cvlr::nondet::nondet_in(0..=100))
+    }
+}
+#[cvlr(assume = "matches!(self, Side::Bid(_))")]
+enum Side {
+    Bid(#[cvlr(range = 1..10)] u64),
+    Ask { #[cvlr(default)] size: u64 },
+}
+impl ::cvlr::nondet::Nondet for Side {
+    fn nondet() -> Side {
+        let __cvlr_nondet = match ::cvlr::nondet::nondet::<u64>() {
+            0u64 => Side::Bid(::cvlr::nondet::nondet_in(1..10)),
+            _ => {
+                Side::Ask {
+                    size: ::core::default::Default::default(),
+                }
+            }
+        };
+        {
+            let __cvlr_self = &__cvlr_nondet;
+            {
+                let c_ = #[allow(non_exhaustive_omitted_patterns)]
+                match __cvlr_self {
+                    Side::Bid(_) => true,
+                    _ => false,
+                };
+                ::cvlr_asserts::log::add_desc("matches!(self, Side::Bid(_))");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(
+                    c_,
+                    "matches!(self, Side::Bid(_))",
+                );
+            };
+        }
+        __cvlr_nondet
+    }
+}
+fn main() {}
@@ -0,0 +1,34 @@
+use cvlr::nondet::Nondet;
+use cvlr_derive::Nondet;
+
+fn even() -> u64 {
+    2
+}
+
+#[derive(Nondet)]
+#[cvlr(assume = "self.lo <= self.hi")]
+struct Interval {
+    #[cvlr(range = 0..1000)]
+    lo: u64,
+    #[cvlr(assume = "self.hi > 0")]
+    hi: u64,
+    #[cvlr(default)]
+    hits: u64,
+    #[cvlr(with = even)]
+    step: u64,
+}
+
+#[derive(Nondet)]
+struct Percent(#[cvlr(range = 0..=100)] u8);
+
+#[derive(Nondet)]
+#[cvlr(assume = "matches!(self, Side::Bid(_))")]
+enum Side {
+    Bid(#[cvlr(range = 1..10)] u64),
+    Ask {
+        #[cvlr(default)]
+        size: u64,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+use cvlr::nondet::Nondet;
+use cvlr_derive::Nondet;
+struct Pool<T> {
+    reserve: T,
+    fee: u64,
+}
+impl<T> ::cvlr::nondet::Nondet for Pool<T>
+where
+    T: ::cvlr::nondet::Nondet,
+{
+    fn nondet() -> Pool<T> {
+        Pool {
+            reserve: ::cvlr::nondet::nondet(),
+            fee: ::cvlr::nondet::nondet(),
+        }
+    }
+}
+struct Wrapper<const N: usize>([u64; N]);
+impl<const N: usize> ::cvlr::nondet::Nondet for Wrapper<N> {
+    fn nondet() -> Wrapper<N> {
+        Wrapper(::cvlr::nondet::nondet())
+    }
+}
+enum Either<A, B>
+where
+    A: Copy,
+{
+    Left(A),
+    Right { value: B },
+}
+impl<A, B> ::cvlr::nondet::Nondet for Either<A, B>
+where
+    A: Copy,
+    A: ::cvlr::nondet::Nondet,
+    B: ::cvlr::nondet::Nondet,
+{
+    fn nondet() -> Either<A, B> {
+        match ::cvlr::nondet::nondet::<u64>() {
+            0u64 => Either::Left(::cvlr::nondet::nondet()),
+            _ => {
+                Either::Right {
+                    value: ::cvlr::nondet::nondet(),
+                }
+            }
+        }
+    }
+}
+pub trait Market {
+    type Price;
+}
+#[cvlr(bound = "M::Price: Nondet")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+impl<M: Market> ::cvlr::nondet::Nondet for Quote<M>
+where
+    M::Price: Nondet,
+{
+    fn nondet() -> Quote<M> {
+        Quote {
+            price: ::cvlr::nondet::nondet(),
+        }
+    }
+}
+fn main() {}
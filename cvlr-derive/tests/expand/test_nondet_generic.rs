@@ -0,0 +1,32 @@
+use cvlr::nondet::Nondet;
+use cvlr_derive::Nondet;
+
+#[derive(Nondet)]
+struct Pool<T> {
+    reserve: T,
+    fee: u64,
+}
+
+#[derive(Nondet)]
+struct Wrapper<const N: usize>([u64; N]);
+
+#[derive(Nondet)]
+enum Either<A, B>
+where
+    A: Copy,
+{
+    Left(A),
+    Right { value: B },
+}
+
+pub trait Market {
+    type Price;
+}
+
+#[derive(Nondet)]
+#[cvlr(bound = "M::Price: Nondet")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+
+fn main() {}
@@ -0,0 +1,111 @@
+use cvlr::prelude::*;
+
+#[derive(Clone, CvlrFrame)]
+struct Account {
+    owner: u64,
+    balance: u64,
+    frozen: bool,
+}
+
+#[derive(Clone, CvlrFrame)]
+struct Pair(u64, i64);
+
+#[derive(Clone, CvlrFrame)]
+struct Wrapper<T> {
+    inner: T,
+    count: u64,
+}
+
+fn account() -> Account {
+    Account {
+        owner: 7,
+        balance: 100,
+        frozen: false,
+    }
+}
+
+#[test]
+fn test_fields() {
+    assert_eq!(Account::FIELDS, &["owner", "balance", "frozen"]);
+    assert_eq!(Pair::FIELDS, &["0", "1"]);
+}
+
+#[test]
+fn test_eval_frame() {
+    let pre = account();
+    let mut post = account();
+    post.balance = 150;
+
+    assert!(Frame::<Account>::unchanged().eval_with_states(&pre, &pre));
+    assert!(!Frame::<Account>::unchanged().eval_with_states(&post, &pre));
+    assert!(cvlr_frame!(Account, except: [balance]).eval_with_states(&post, &pre));
+    assert!(!Frame::<Account>::except(&["owner"]).eval_with_states(&post, &pre));
+    assert!(!cvlr_frame!(Account).eval_with_states(&post, &pre));
+}
+
+#[test]
+fn test_assert_frame_tuple_and_generic() {
+    let pre = Pair(1, -1);
+    let post = Pair(1, -2);
+    cvlr_frame!(Pair, except: [1]).assert_with_states(&post, &pre);
+
+    let pre = Wrapper {
+        inner: 3u32,
+        count: 0,
+    };
+    let post = Wrapper {
+        inner: 3u32,
+        count: 1,
+    };
+    Frame::<Wrapper<u32>>::except(&["count"]).assert_with_states(&post, &pre);
+    assert!(!Frame::<Wrapper<u32>>::unchanged().eval_with_states(&post, &pre));
+}
+
+#[test]
+#[should_panic(expected = "balance unchanged")]
+fn test_assert_frame_fails_on_mutated_field() {
+    let pre = account();
+    let mut post = account();
+    post.balance = 150;
+    Frame::<Account>::except(&["frozen"]).assert_with_states(&post, &pre);
+}
+
+#[test]
+fn test_assert_frame_logs_compared_fields() {
+    let pre = account();
+    let mut post = account();
+    post.balance = 150;
+    cvlr::log::rt::clear_trace();
+    Frame::<Account>::except(&["balance"]).assert_with_states(&post, &pre);
+    let trace = cvlr::log::rt::take_trace().to_string();
+    assert!(trace.contains("owner unchanged"), "{}", trace);
+    assert!(trace.contains("post.frozen"), "{}", trace);
+    assert!(!trace.contains("post.balance"), "{}", trace);
+}
+
+#[test]
+fn test_assume_frame() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let pre = account();
+            let mut post = account();
+            post.owner = cvlr::nondet::nondet();
+            Frame::<Account>::unchanged().assume_with_states(&post, &pre);
+            assert_eq!(post.owner, pre.owner);
+        },
+        50,
+    );
+    report.assert_success();
+}
+
+#[test]
+fn test_changed() {
+    let frame = cvlr_frame!(Account, except: [frozen, owner]);
+    assert_eq!(frame.changed().collect::<Vec<_>>(), ["owner", "frozen"]);
+}
+
+#[test]
+#[should_panic(expected = "unknown field")]
+fn test_except_unknown_field() {
+    let _ = Frame::<Account>::except(&["balanse"]);
+}
@@ -114,3 +114,60 @@ fn test_enum_with_named_log() {
     e2.log("e2", &mut logger);
     e3.log("e3", &mut logger);
 }
+
+// Test generic structs and enums
+#[derive(CvlrLog)]
+struct Pool<'a, T> {
+    reserve: &'a T,
+    fee: u64,
+}
+
+#[derive(CvlrLog)]
+struct Scaled<const DECIMALS: u32>(u64);
+
+#[derive(CvlrLog)]
+enum Either<A, B> {
+    Left(A),
+    Right { value: B },
+}
+
+trait Market {
+    type Price;
+}
+
+// Does not implement CvlrLog, so the default `M: CvlrLog` bound does not hold
+struct Usd;
+
+impl Market for Usd {
+    type Price = u64;
+}
+
+#[derive(CvlrLog)]
+#[cvlr(bound = "M::Price: CvlrLog")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+
+#[test]
+fn test_generic_log() {
+    let reserve = 7u64;
+    let pool = Pool {
+        reserve: &reserve,
+        fee: 3,
+    };
+    let scaled = Scaled::<6>(1_000_000);
+    let e1: Either<u64, i32> = Either::Left(1);
+    let e2: Either<u64, i32> = Either::Right { value: -1 };
+    let mut logger = cvlr::log::CvlrLogger::new();
+    pool.log("pool", &mut logger);
+    scaled.log("scaled", &mut logger);
+    e1.log("e1", &mut logger);
+    e2.log("e2", &mut logger);
+}
+
+#[test]
+fn test_bound_attribute_log() {
+    let q = Quote::<Usd> { price: 5 };
+    let mut logger = cvlr::log::CvlrLogger::new();
+    q.log("quote", &mut logger);
+}
@@ -66,6 +66,17 @@ enum MixedEnum {
     Named { x: u64, y: bool },
 }
 
+// Test struct with compound field types
+#[derive(Nondet)]
+struct CompoundFields {
+    arr: [u64; 4],
+    pair: (u8, bool),
+    res: Result<u32, i8>,
+    nz: core::num::NonZeroU64,
+    c: char,
+    boxed: Box<Nested>,
+}
+
 #[test]
 fn test_unit_struct() {
     let _unit = UnitStruct::nondet();
@@ -84,9 +95,9 @@ fn test_named_fields() {
 fn test_unnamed_fields() {
     let tuple = UnnamedFields::nondet();
     // Just verify it compiles and can be accessed
-    let _0 = tuple.0;
-    let _1 = tuple.1;
-    let _2 = tuple.2;
+    let _f0 = tuple.0;
+    let _f1 = tuple.1;
+    let _f2 = tuple.2;
 }
 
 #[test]
@@ -108,6 +119,17 @@ fn test_nested_struct() {
     let _value = nested.value;
 }
 
+#[test]
+fn test_compound_fields() {
+    let compound = CompoundFields::nondet();
+    let _arr = compound.arr;
+    let _pair = compound.pair;
+    let _res = compound.res;
+    let _nz = compound.nz;
+    let _c = compound.c;
+    let _boxed = compound.boxed;
+}
+
 #[test]
 fn test_nondet_trait_method() {
     // Test that the generated impl works with the trait method
@@ -165,6 +187,151 @@ fn test_enum_nondet_trait_method() {
     }
 }
 
+// Test generic structs and enums
+#[derive(Nondet)]
+struct Pool<T> {
+    reserve: T,
+    fee: u64,
+}
+
+#[derive(Nondet)]
+struct Wrapper<const N: usize>([u64; N]);
+
+#[derive(Nondet)]
+enum Either<A, B> {
+    Left(A),
+    Right { value: B },
+}
+
+trait Market {
+    type Price;
+}
+
+// Does not implement Nondet, so the default `M: Nondet` bound does not hold
+struct Usd;
+
+impl Market for Usd {
+    type Price = u64;
+}
+
+#[derive(Nondet)]
+#[cvlr(bound = "M::Price: Nondet")]
+struct Quote<M: Market> {
+    price: M::Price,
+}
+
+// Derives that need different bounds give them per derive
+#[derive(Nondet, cvlr_derive::CvlrLog)]
+#[cvlr(bound(nondet = "M::Price: Nondet", log = "M::Price: cvlr::log::CvlrLog"))]
+struct LoggedQuote<M: Market> {
+    price: M::Price,
+}
+
+#[test]
+fn test_generic_struct() {
+    let pool: Pool<i32> = Pool::nondet();
+    let _reserve: i32 = pool.reserve;
+    let pools = <Pool<Pool<u8>>>::nondet();
+    let _inner: u8 = pools.reserve.reserve;
+}
+
+#[test]
+fn test_const_generic_struct() {
+    let w = Wrapper::<4>::nondet();
+    assert_eq!(w.0.len(), 4);
+}
+
+#[test]
+fn test_generic_enum() {
+    match Either::<u8, i64>::nondet() {
+        Either::Left(_) => {}
+        Either::Right { value: _ } => {}
+    }
+}
+
+#[test]
+fn test_bound_attribute() {
+    let q: Quote<Usd> = Quote::nondet();
+    let _price: u64 = q.price;
+}
+
+#[test]
+fn test_bound_per_derive() {
+    let q: LoggedQuote<Usd> = LoggedQuote::nondet();
+    cvlr::log::clog("q", &q);
+}
+
+// Test field and type constraints
+fn one() -> u64 {
+    1
+}
+
+#[derive(Nondet)]
+#[cvlr(assume = "self.lo <= self.hi")]
+struct Interval {
+    #[cvlr(range = 0..1000)]
+    lo: u64,
+    #[cvlr(range = 0..2000)]
+    #[cvlr(assume = "self.hi > 0")]
+    hi: u64,
+    #[cvlr(default)]
+    hits: u64,
+    #[cvlr(with = one)]
+    step: u64,
+}
+
+#[derive(Nondet)]
+struct Percent(#[cvlr(range = 0..=100)] u8);
+
+#[derive(Nondet)]
+#[cvlr(assume = "!matches!(self, Side::Ask { size: 0 })")]
+enum Side {
+    Bid(#[cvlr(range = 1..10)] u64),
+    Ask { size: u64 },
+}
+
+#[test]
+fn test_field_constraints() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let i = Interval::nondet();
+            assert!(i.lo < 1000);
+            assert!(i.lo <= i.hi && i.hi > 0 && i.hi < 2000);
+            assert_eq!(i.hits, 0);
+            assert_eq!(i.step, 1);
+            assert!(Percent::nondet().0 <= 100);
+        },
+        50,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+}
+
+#[test]
+fn test_enum_constraints() {
+    let report = cvlr::rt::run_rule(
+        || match Side::nondet() {
+            Side::Bid(price) => assert!((1..10).contains(&price)),
+            Side::Ask { size } => assert_ne!(size, 0),
+        },
+        50,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+}
+
+#[test]
+fn test_nondet_with_composes_with_constraints() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let i = Interval::nondet_with(|i| i.lo % 2 == 0);
+            assert!(i.lo < 1000 && i.lo % 2 == 0);
+        },
+        50,
+    );
+    report.assert_success();
+}
+
 #[test]
 fn expand_tests() {
     macrotest::expand("tests/expand/*.rs");
@@ -174,4 +341,7 @@ fn expand_tests() {
 fn ui_tests() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/expand/test_nondet_union.rs");
+    t.compile_fail("tests/expand/test_nondet_bad_attrs.rs");
+    t.compile_fail("tests/expand/test_cvlr_frame_enum.rs");
+    t.compile_fail("tests/expand/test_cvlr_frame_unknown_field.rs");
 }
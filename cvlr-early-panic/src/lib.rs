@@ -1,12 +1,274 @@
+use cvlr_macro_utils::exits::macro_exits;
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::visit_mut::{self, VisitMut};
-use syn::{parse_macro_input, parse_quote, Expr, ItemFn, Stmt};
+use syn::{
+    parse_macro_input, parse_quote, Block, Expr, ExprBlock, ExprBreak, ExprLit, Item, ItemFn,
+    Lifetime, Lit, Macro, MetaNameValue, ReturnType, Stmt, StmtMacro, Token, Type,
+};
+
+/// How an error path is cut
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// `panic!()`, and `?` becomes `.unwrap()`
+    Panic,
+    /// Log the error and `cvlr_assume!(false)`
+    Assume,
+    /// Log the error and `cvlr_assert!(false)`
+    Assert,
+    /// Log the error and `panic!()`
+    Log,
+}
+
+/// Arguments of `#[early_panic(...)]`
+struct Options {
+    mode: Mode,
+    /// Rewrite the bodies of closures, by default only in `panic` mode
+    closures: bool,
+    /// Rewrite the bodies of `async` blocks, by default only in `panic` mode
+    async_blocks: bool,
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut mode = Mode::Panic;
+        let mut closures = None;
+        let mut async_blocks = None;
+        for arg in Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)? {
+            let lit = match &arg.value {
+                Expr::Lit(ExprLit { lit, .. }) => lit,
+                value => return Err(syn::Error::new_spanned(value, "expected a literal")),
+            };
+            match lit {
+                Lit::Str(value) if arg.path.is_ident("mode") => {
+                    mode = match value.value().as_str() {
+                        "panic" => Mode::Panic,
+                        "assume" => Mode::Assume,
+                        "assert" => Mode::Assert,
+                        "log" => Mode::Log,
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected one of `panic`, `assume`, `assert`, or `log`",
+                            ))
+                        }
+                    }
+                }
+                Lit::Bool(value) if arg.path.is_ident("closures") => closures = Some(value.value),
+                Lit::Bool(value) if arg.path.is_ident("async_blocks") => {
+                    async_blocks = Some(value.value)
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &arg.path,
+                        "expected `mode = \"...\"`, `closures = bool`, or `async_blocks = bool`",
+                    ))
+                }
+            }
+        }
+        // -- `panic` mode keeps rewriting nested bodies, as `?` always did
+        let nested = mode == Mode::Panic;
+        Ok(Options {
+            mode,
+            closures: closures.unwrap_or(nested),
+            async_blocks: async_blocks.unwrap_or(nested),
+        })
+    }
+}
+
+/// What the body being rewritten returns
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Returns {
+    Option,
+    /// A `Result`, or any other type that `?` works on
+    Result,
+    /// Not known, for a closure without a return type or an `async` block
+    Unknown,
+}
+
+impl Returns {
+    fn of(ret_type: &ReturnType) -> Self {
+        match ret_type {
+            ReturnType::Type(_, ty) => match &**ty {
+                Type::Path(p)
+                    if p.path
+                        .segments
+                        .last()
+                        .is_some_and(|seg| seg.ident == "Option") =>
+                {
+                    Returns::Option
+                }
+                Type::Infer(_) => Returns::Unknown,
+                _ => Returns::Result,
+            },
+            ReturnType::Default => Returns::Unknown,
+        }
+    }
+}
 
 /// Replaces question mark operator by unwrap
-struct EarlyPanic;
+///
+/// Error values, `Err(..)` and (when returning an `Option`) `None`, are cut
+/// when they are returned: with `return`, or in a tail position of the body,
+/// which includes the tails of `if`, `match`, and block expressions in a tail
+/// position.
+struct EarlyPanic {
+    opts: Options,
+    /// What the innermost body being rewritten returns
+    returns: Returns,
+    errors: Vec<syn::Error>,
+}
 
 impl EarlyPanic {
+    fn new(opts: Options, ret_type: &ReturnType) -> Self {
+        Self {
+            opts,
+            returns: match Returns::of(ret_type) {
+                // -- a function without a return type returns `()`
+                Returns::Unknown => Returns::Result,
+                returns => returns,
+            },
+            errors: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, tokens: impl ToTokens, msg: &str) {
+        self.errors.push(syn::Error::new_spanned(tokens, msg));
+    }
+
+    /// The error carried by a returned `expr`, if `expr` is an error value
+    ///
+    /// `Some(Some(e))` for `Err(e)`, and `Some(None)` for `None`.
+    fn returned_error<'a>(&self, expr: &'a Expr) -> Option<Option<&'a Expr>> {
+        if Self::is_err_expr(expr) {
+            Some(Self::err_value(expr))
+        } else if self.returns == Returns::Option && Self::is_none_expr(expr) {
+            Some(None)
+        } else {
+            None
+        }
+    }
+
+    /// Rewrites the error values in tail positions of `block`
+    fn rewrite_block_tail(&mut self, block: &mut Block) {
+        if let Some(Stmt::Expr(expr, None)) = block.stmts.last_mut() {
+            if let Some(err) = self.returned_error(expr) {
+                let cut = self.cut(err.map(|e| (e, e)));
+                *block.stmts.last_mut().unwrap() = parse_quote!(#cut;);
+            } else {
+                self.rewrite_tail(expr);
+            }
+        }
+    }
+
+    /// Rewrites the error values in tail positions of `expr`
+    fn rewrite_tail(&mut self, expr: &mut Expr) {
+        if let Some(err) = self.returned_error(expr) {
+            let cut = self.cut(err.map(|e| (e, e)));
+            *expr = parse_quote!(#cut);
+            return;
+        }
+        match expr {
+            Expr::If(expr_if) => {
+                self.rewrite_block_tail(&mut expr_if.then_branch);
+                if let Some((_, else_branch)) = &mut expr_if.else_branch {
+                    self.rewrite_tail(else_branch);
+                }
+            }
+            Expr::Match(expr_match) => {
+                for arm in &mut expr_match.arms {
+                    self.rewrite_tail(&mut arm.body);
+                }
+            }
+            Expr::Block(expr_block) => {
+                if let Some(label) = &expr_block.label {
+                    self.check_breaks(Some(&label.name), false, &mut expr_block.block);
+                }
+                self.rewrite_block_tail(&mut expr_block.block)
+            }
+            Expr::Loop(expr_loop) => {
+                let label = expr_loop.label.as_ref().map(|l| &l.name);
+                self.check_breaks(label, true, &mut expr_loop.body);
+            }
+            Expr::Unsafe(expr_unsafe) => self.rewrite_block_tail(&mut expr_unsafe.block),
+            Expr::Paren(paren) => self.rewrite_tail(&mut paren.expr),
+            _ => {}
+        }
+    }
+
+    /// Reports error values given to `break` for a loop or labeled block in a
+    /// tail position, which returns them and cannot be cut
+    fn check_breaks(&mut self, label: Option<&Lifetime>, is_loop: bool, body: &mut Block) {
+        let mut finder = BreakFinder {
+            early_panic: self,
+            label,
+            is_loop,
+            loop_depth: 0,
+            found: Vec::new(),
+        };
+        finder.visit_block_mut(body);
+        for brk in finder.found {
+            self.error(
+                brk,
+                "early_panic cannot cut an error value returned with `break`; use `return` instead",
+            );
+        }
+    }
+
+    /// Rewrites a closure or `async` body, which returns `returns`
+    fn rewrite_nested(&mut self, returns: Returns, body: &mut Expr) {
+        let outer = std::mem::replace(&mut self.returns, returns);
+        // -- rewrite `?` first, so that the error values logged by cuts are final
+        self.visit_expr_mut(body);
+        self.rewrite_tail(body);
+        self.returns = outer;
+    }
+
+    /// Reports `?` and `return` inside macro invocations, which cannot be rewritten
+    fn check_macro(&mut self, mac: &Macro) {
+        if macro_exits(mac.tokens.clone()) {
+            self.error(
+                mac,
+                "early_panic cannot rewrite `?` or `return` inside a macro invocation",
+            );
+        }
+    }
+
+    /// Cuts the current path, after logging the error (if any)
+    ///
+    /// The error is given with the expression whose source text tags it.
+    fn cut(&self, err: Option<(&Expr, &Expr)>) -> TokenStream2 {
+        let log = err.map(|(e, src)| quote! { ::cvlr::clog!(#e => stringify!(#src)); });
+        match self.opts.mode {
+            Mode::Panic => quote! { panic!() },
+            Mode::Assume => quote! {{
+                #log
+                ::cvlr::asserts::cvlr_assume!(false, "early_panic");
+                ::core::unreachable!()
+            }},
+            Mode::Assert => quote! {{
+                #log
+                ::cvlr::asserts::cvlr_assert!(false, "early_panic");
+                ::core::unreachable!()
+            }},
+            Mode::Log => quote! {{
+                #log
+                panic!()
+            }},
+        }
+    }
+
+    /// The error in `Err(e)`
+    fn err_value(expr: &Expr) -> Option<&Expr> {
+        match expr {
+            Expr::Call(call) if Self::is_err_expr(expr) => call.args.first(),
+            _ => None,
+        }
+    }
+
     /// Check if an expression is `Err(...)`
     fn is_err_expr(expr: &Expr) -> bool {
         match expr {
@@ -27,55 +289,176 @@ impl EarlyPanic {
             _ => false,
         }
     }
+
+    /// Check if an expression is `None` or `Option::None`
+    fn is_none_expr(expr: &Expr) -> bool {
+        match expr {
+            Expr::Path(path_expr) => {
+                let segments = &path_expr.path.segments;
+                segments.last().is_some_and(|seg| seg.ident == "None")
+                    && (segments.len() == 1 || segments[segments.len() - 2].ident == "Option")
+            }
+            _ => false,
+        }
+    }
 }
 
-impl VisitMut for EarlyPanic {
+/// Finds the `break`s with an error value that leave a loop or labeled block
+struct BreakFinder<'a> {
+    early_panic: &'a EarlyPanic,
+    label: Option<&'a Lifetime>,
+    /// Whether the target is a loop, which unlabeled `break`s also leave
+    is_loop: bool,
+    /// Number of nested loops, which unlabeled `break`s leave instead
+    loop_depth: usize,
+    found: Vec<ExprBreak>,
+}
+
+impl VisitMut for BreakFinder<'_> {
     fn visit_expr_mut(&mut self, node: &mut Expr) {
-        if let Expr::Try(expr) = &mut *node {
-            let prefix: &mut Expr = expr.expr.as_mut();
-            // -- recurse on prefix since it might have nested q-mark
-            visit_mut::visit_expr_mut(self, prefix);
-            *node = parse_quote!(#prefix.unwrap());
-            return;
+        match node {
+            Expr::Break(brk) => {
+                let leaves_target = match &brk.label {
+                    Some(label) => self.label == Some(label),
+                    None => self.is_loop && self.loop_depth == 0,
+                };
+                let is_error = brk
+                    .expr
+                    .as_deref()
+                    .is_some_and(|v| self.early_panic.returned_error(v).is_some());
+                if leaves_target && is_error {
+                    self.found.push(brk.clone());
+                }
+                visit_mut::visit_expr_break_mut(self, brk);
+            }
+            Expr::ForLoop(_) | Expr::Loop(_) | Expr::While(_) => {
+                self.loop_depth += 1;
+                visit_mut::visit_expr_mut(self, node);
+                self.loop_depth -= 1;
+            }
+            // -- `break` cannot leave these
+            Expr::Closure(_) | Expr::Async(_) | Expr::Const(_) => {}
+            _ => visit_mut::visit_expr_mut(self, node),
         }
-
-        // -- recurse on other expression types
-        visit_mut::visit_expr_mut(self, node);
     }
 
-    fn visit_stmt_mut(&mut self, node: &mut Stmt) {
+    fn visit_item_mut(&mut self, _node: &mut Item) {}
+}
+
+impl VisitMut for EarlyPanic {
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
         match node {
-            Stmt::Expr(expr, _) => {
-                // Recurse first to handle nested expressions (including ? operators)
-                visit_mut::visit_expr_mut(self, expr);
-
-                // Check if this expression should panic
-                // Use a temporary immutable reference for pattern matching
-                let is_return_err = {
-                    match expr {
-                        Expr::Return(ret_expr) => {
-                            ret_expr.expr.as_ref().is_some_and(|v| Self::is_err_expr(v))
+            Expr::Try(expr) => {
+                let prefix: &mut Expr = expr.expr.as_mut();
+                // -- recurse on prefix since it might have nested q-mark
+                self.visit_expr_mut(prefix);
+                *node = if self.opts.mode == Mode::Panic {
+                    parse_quote!(#prefix.unwrap())
+                } else {
+                    match self.returns {
+                        Returns::Option => {
+                            let cut = self.cut(None);
+                            parse_quote! {
+                                match #prefix {
+                                    ::core::option::Option::Some(__cvlr_v) => __cvlr_v,
+                                    ::core::option::Option::None => #cut,
+                                }
+                            }
+                        }
+                        Returns::Result => {
+                            let cut = self.cut(Some((&parse_quote!(__cvlr_e), prefix)));
+                            parse_quote! {
+                                match #prefix {
+                                    ::core::result::Result::Ok(__cvlr_v) => __cvlr_v,
+                                    ::core::result::Result::Err(__cvlr_e) => #cut,
+                                }
+                            }
+                        }
+                        Returns::Unknown => {
+                            self.error(
+                                &*node,
+                                "early_panic cannot tell whether `?` applies to a `Result` or an `Option` here; annotate the closure with its return type",
+                            );
+                            return;
                         }
-                        _ => false,
                     }
                 };
-
-                let is_bare_err = { !matches!(*expr, Expr::Return(_)) && Self::is_err_expr(expr) };
-
-                if is_return_err || is_bare_err {
-                    *node = parse_quote!(panic!(););
+            }
+            Expr::Return(ret_expr) => {
+                if let Some(value) = &mut ret_expr.expr {
+                    self.visit_expr_mut(value);
+                }
+                let err = ret_expr
+                    .expr
+                    .as_deref()
+                    .and_then(|v| self.returned_error(v));
+                if let Some(err) = err {
+                    let cut = self.cut(err.map(|e| (e, e)));
+                    *node = parse_quote!(#cut);
+                } else if let Some(value) = &mut ret_expr.expr {
+                    self.rewrite_tail(value);
                 }
             }
-            _ => {
-                // Recurse on other statement types
-                visit_mut::visit_stmt_mut(self, node);
+            Expr::Closure(closure) => {
+                if self.opts.closures {
+                    let returns = Returns::of(&closure.output);
+                    self.rewrite_nested(returns, &mut closure.body);
+                }
             }
+            Expr::Async(expr_async) => {
+                if self.opts.async_blocks {
+                    let mut body = Expr::Block(ExprBlock {
+                        attrs: Vec::new(),
+                        label: None,
+                        block: expr_async.block.clone(),
+                    });
+                    self.rewrite_nested(Returns::Unknown, &mut body);
+                    if let Expr::Block(b) = body {
+                        expr_async.block = b.block;
+                    }
+                }
+            }
+            Expr::Macro(expr_macro) => self.check_macro(&expr_macro.mac),
+            // -- recurse on other expression types
+            _ => visit_mut::visit_expr_mut(self, node),
         }
     }
+
+    fn visit_stmt_macro_mut(&mut self, node: &mut StmtMacro) {
+        self.check_macro(&node.mac);
+    }
+
+    // -- nested items are not rewritten
+    fn visit_item_mut(&mut self, _node: &mut Item) {}
 }
 
 /// Attribute to replace question mark operator by unwrap.
 ///
+/// Error paths panic by default: `?`, and error values that are returned,
+/// either with `return` or in a tail position of the function (including the
+/// tails of `if`, `match`, and blocks in a tail position). Error values are
+/// `Err(..)`, and `None` in a function returning an `Option`. With
+/// `mode = "..."`, they are cut differently:
+///
+/// - `mode = "assume"` logs the error with `clog!` and assumes `false`, so
+///   error paths are not explored
+/// - `mode = "assert"` logs the error and asserts `false`, so reaching an
+///   error path is a violation
+/// - `mode = "log"` logs the error and panics
+///
+/// Logging requires the error type to implement `CvlrLog`. In these modes, `?`
+/// is expanded according to the return type: a function returning `Option`
+/// cuts on `None`, and any other function on `Err`.
+///
+/// In the default `panic` mode, the bodies of closures and `async` blocks are
+/// rewritten too, unless `closures = false` or `async_blocks = false` is given.
+/// In the other modes, they handle their own errors and are left alone, unless
+/// `closures = true` or `async_blocks = true` is given; a closure that uses `?`
+/// must then be annotated with its return type. `?` and
+/// `return` inside macro invocations cannot be rewritten, and are reported as
+/// errors, as are error values given to `break` for a loop or labeled block
+/// whose value is returned.
+///
 /// # Example
 ///
 /// ```
@@ -86,9 +469,22 @@ impl VisitMut for EarlyPanic {
 ///     Some(v)
 /// }
 /// ```
+///
+/// ```ignore
+/// #[early_panic(mode = "assume")]
+/// fn withdraw(vault: &mut Vault, amount: u64) -> Result<(), VaultError> {
+///     // -- the error is logged, then the path is cut with `cvlr_assume!(false)`
+///     vault.balance = vault.balance.checked_sub(amount).ok_or(VaultError::Insufficient)?;
+///     Ok(())
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn early_panic(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn early_panic(args: TokenStream, input: TokenStream) -> TokenStream {
+    let opts = parse_macro_input!(args as Options);
     let mut fn_ast = parse_macro_input!(input as ItemFn);
-    EarlyPanic.visit_item_fn_mut(&mut fn_ast);
-    TokenStream::from(quote!(#fn_ast))
+    let mut early_panic = EarlyPanic::new(opts, &fn_ast.sig.output);
+    early_panic.visit_block_mut(&mut fn_ast.block);
+    early_panic.rewrite_block_tail(&mut fn_ast.block);
+    let errors = early_panic.errors.iter().map(syn::Error::to_compile_error);
+    TokenStream::from(quote!(#fn_ast #(#errors)*))
 }
@@ -0,0 +1,2 @@
+use cvlr_early_panic::early_panic;
+fn main() {}
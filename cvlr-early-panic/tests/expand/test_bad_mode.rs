@@ -0,0 +1,13 @@
+use cvlr_early_panic::early_panic;
+
+#[early_panic(mode = "ignore")]
+fn test_bad_mode() -> Result<u64, u64> {
+    Err(42)
+}
+
+#[early_panic(kind = "assume")]
+fn test_bad_key() -> Result<u64, u64> {
+    Err(42)
+}
+
+fn main() {}
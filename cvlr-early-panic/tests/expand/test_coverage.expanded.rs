@@ -0,0 +1,74 @@
+use cvlr_early_panic::early_panic;
+fn test_match_arms(x: Option<u64>) -> Result<u64, u64> {
+    match x {
+        Some(0) => ::core::panicking::panic("explicit panic"),
+        Some(v) => Ok(v),
+        None => ::core::panicking::panic("explicit panic"),
+    }
+}
+fn test_option(x: Option<u64>) -> Option<u64> {
+    let v = x.unwrap();
+    if v == 0 {
+        ::core::panicking::panic("explicit panic");
+    }
+    if v > 10 {
+        ::core::panicking::panic("explicit panic");
+    } else {
+        Some(v)
+    }
+}
+fn test_nested_tails(x: u64) -> Result<u64, u64> {
+    if x > 10 {
+        if x > 20 {
+            ::core::panicking::panic("explicit panic");
+        } else {
+            Ok(x)
+        }
+    } else {
+        let y = { x + 1 };
+        match y {
+            1 => {
+                let z = y * 2;
+                ::core::panicking::panic("explicit panic");
+            }
+            _ => Ok(y),
+        }
+    }
+}
+fn test_closures_skipped(x: &[u64]) -> Result<u64, u64> {
+    let checked = |v: u64| if v > 10 { Err(v) } else { Ok(v) };
+    let v = checked(x[0]).unwrap();
+    Ok(v)
+}
+fn test_closures_default(x: &[u64]) -> Result<u64, u64> {
+    let first = |i: usize| -> Option<u64> { Some(*x.get(i).unwrap()) };
+    Ok(first(0).unwrap_or(0))
+}
+fn test_closures(x: &[u64]) -> Result<u64, u64> {
+    let first = || -> Option<u64> {
+        let v = x.first().unwrap();
+        if *v == 0 {
+            ::core::panicking::panic("explicit panic");
+        }
+        Some(*v)
+    };
+    first().ok_or(0)
+}
+fn test_async_blocks(x: u64) -> Result<u64, u64> {
+    let _f = async move {
+        if x == 0 {
+            {
+                ::cvlr_log::cvlr_log("x", &(x));
+                {
+                    let c_ = false;
+                    ::cvlr_asserts::log::add_desc("early_panic");
+                    ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "early_panic");
+                };
+                ::core::panicking::panic("internal error: entered unreachable code")
+            };
+        }
+        Ok(x)
+    };
+    Ok(x)
+}
+fn main() {}
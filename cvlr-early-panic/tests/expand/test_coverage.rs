@@ -0,0 +1,83 @@
+use cvlr_early_panic::early_panic;
+
+#[early_panic]
+fn test_match_arms(x: Option<u64>) -> Result<u64, u64> {
+    match x {
+        Some(0) => return Err(0),
+        Some(v) => Ok(v),
+        None => Err(1),
+    }
+}
+
+#[early_panic]
+fn test_option(x: Option<u64>) -> Option<u64> {
+    let v = x?;
+    if v == 0 {
+        return None;
+    }
+    if v > 10 {
+        None
+    } else {
+        Some(v)
+    }
+}
+
+#[early_panic]
+fn test_nested_tails(x: u64) -> Result<u64, u64> {
+    if x > 10 {
+        if x > 20 {
+            Err(20)
+        } else {
+            Ok(x)
+        }
+    } else {
+        let y = { x + 1 };
+        match y {
+            1 => {
+                let z = y * 2;
+                Err(z)
+            }
+            _ => Ok(y),
+        }
+    }
+}
+
+#[early_panic(closures = false)]
+fn test_closures_skipped(x: &[u64]) -> Result<u64, u64> {
+    // -- the closure handles its own errors
+    let checked = |v: u64| if v > 10 { Err(v) } else { Ok(v) };
+    let v = checked(x[0])?;
+    Ok(v)
+}
+
+#[early_panic]
+fn test_closures_default(x: &[u64]) -> Result<u64, u64> {
+    // -- in panic mode, closures are rewritten unless `closures = false`
+    let first = |i: usize| -> Option<u64> { Some(*x.get(i)?) };
+    Ok(first(0).unwrap_or(0))
+}
+
+#[early_panic(closures = true)]
+fn test_closures(x: &[u64]) -> Result<u64, u64> {
+    let first = || -> Option<u64> {
+        let v = x.first()?;
+        if *v == 0 {
+            return None;
+        }
+        Some(*v)
+    };
+    first().ok_or(0)
+}
+
+#[early_panic(mode = "assume", async_blocks = true)]
+fn test_async_blocks(x: u64) -> Result<u64, u64> {
+    let _f = async move {
+        if x == 0 {
+            return Err(x);
+        }
+        Ok(x)
+    };
+    Ok(x)
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+use cvlr_early_panic::early_panic;
+fn test_assume(x: u64) -> Result<u64, u64> {
+    let v = match x.checked_add(1).ok_or(0u64) {
+        ::core::result::Result::Ok(__cvlr_v) => __cvlr_v,
+        ::core::result::Result::Err(__cvlr_e) => {
+            ::cvlr_log::cvlr_log("x.checked_add(1).ok_or(0u64)", &(__cvlr_e));
+            {
+                let c_ = false;
+                ::cvlr_asserts::log::add_desc("early_panic");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "early_panic");
+            };
+            ::core::panicking::panic("internal error: entered unreachable code")
+        }
+    };
+    if v > 10 {
+        {
+            ::cvlr_log::cvlr_log("v", &(v));
+            {
+                let c_ = false;
+                ::cvlr_asserts::log::add_desc("early_panic");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "early_panic");
+            };
+            ::core::panicking::panic("internal error: entered unreachable code")
+        };
+    }
+    Ok(v)
+}
+fn test_assume_option(x: Option<u64>) -> Option<u64> {
+    let v = match x {
+        ::core::option::Option::Some(__cvlr_v) => __cvlr_v,
+        ::core::option::Option::None => {
+            {
+                let c_ = false;
+                ::cvlr_asserts::log::add_desc("early_panic");
+                ::cvlr_asserts::cvlr_assume_checked_with_desc(c_, "early_panic");
+            };
+            ::core::panicking::panic("internal error: entered unreachable code")
+        }
+    };
+    Some(v)
+}
+fn test_log(x: u64) -> Result<u64, u64> {
+    if x > 10 {
+        {
+            ::cvlr_log::cvlr_log("x", &(x));
+            ::core::panicking::panic("explicit panic")
+        };
+    } else {
+        Ok(x)
+    }
+}
+fn main() {}
@@ -0,0 +1,27 @@
+use cvlr_early_panic::early_panic;
+
+#[early_panic(mode = "assume")]
+fn test_assume(x: u64) -> Result<u64, u64> {
+    let v = x.checked_add(1).ok_or(0u64)?;
+    if v > 10 {
+        return Err(v);
+    }
+    Ok(v)
+}
+
+#[early_panic(mode = "assume")]
+fn test_assume_option(x: Option<u64>) -> Option<u64> {
+    let v = x?;
+    Some(v)
+}
+
+#[early_panic(mode = "log")]
+fn test_log(x: u64) -> Result<u64, u64> {
+    if x > 10 {
+        Err(x)
+    } else {
+        Ok(x)
+    }
+}
+
+fn main() {}
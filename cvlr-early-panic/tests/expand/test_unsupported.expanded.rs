@@ -0,0 +1,34 @@
+use cvlr_early_panic::early_panic;
+fn test_question_mark_in_macro(x: Result<u64, u64>) -> Result<u64, u64> {
+    {
+        ::std::io::_print(format_args!("{0}\n", x ?));
+    };
+    Ok(0)
+}
+fn test_unannotated_closure(x: Option<u64>) -> Result<u64, u64> {
+    let f = || Some(x? + 1);
+    f().ok_or(0)
+}
+fn test_break_err(xs: &[u64]) -> Result<u64, u64> {
+    let mut i = 0;
+    loop {
+        for x in xs {
+            if *x == 0 {
+                break;
+            }
+        }
+        if i == xs.len() {
+            break Err(0);
+        }
+        i += 1;
+    }
+}
+fn test_break_label_err(x: u64) -> Result<u64, u64> {
+    'done: {
+        if x == 0 {
+            break 'done Err(x);
+        }
+        Ok(x)
+    }
+}
+fn main() {}
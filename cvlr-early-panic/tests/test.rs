@@ -37,3 +37,141 @@ fn test_three_payload(a: u64) -> Result<u64, u64> {
 fn test_three() {
     let _ = test_three_payload(11);
 }
+
+#[early_panic(mode = "assume")]
+fn withdraw_assume(balance: u64, amount: u64) -> Result<u64, u64> {
+    let rest = balance.checked_sub(amount).ok_or(amount)?;
+    if rest == 0 {
+        return Err(balance);
+    }
+    Ok(rest)
+}
+
+#[test]
+fn test_assume_mode_discards_error_paths() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let balance = cvlr::nondet::nondet_in(0u64..10);
+            let amount = cvlr::nondet::nondet_in(0u64..10);
+            let rest = withdraw_assume(balance, amount).unwrap();
+            assert!(rest > 0 && rest == balance - amount);
+        },
+        100,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+    assert!(report.discarded > 0);
+}
+
+#[early_panic(mode = "assert")]
+fn first_assert(v: Option<u64>) -> Option<u64> {
+    let x = v?;
+    Some(x + 1)
+}
+
+#[test]
+fn test_assert_mode_on_none() {
+    assert_eq!(first_assert(Some(1)), Some(2));
+    let report = cvlr::rt::run_rule(
+        || {
+            let _ = first_assert(None);
+        },
+        1,
+    );
+    assert_eq!(report.failures.len(), 1);
+}
+
+#[early_panic(mode = "log")]
+fn parse_log(s: &str) -> Result<u64, u64> {
+    let v = s.parse::<u64>().map_err(|_| 7u64)?;
+    Ok(v)
+}
+
+#[test]
+fn test_log_mode_logs_error() {
+    cvlr::log::rt::clear_trace();
+    let res = std::panic::catch_unwind(|| parse_log("x"));
+    assert!(res.is_err());
+    let trace = cvlr::log::rt::take_trace().to_string();
+    assert!(trace.contains(": 7"), "{}", trace);
+    assert_eq!(parse_log("3"), Ok(3));
+}
+
+#[early_panic(mode = "assume")]
+fn classify(x: Option<u64>) -> Result<u64, u64> {
+    match x {
+        Some(0) => return Err(0),
+        Some(v) if v > 100 => {
+            if v > 200 {
+                Err(v)
+            } else {
+                Ok(v - 100)
+            }
+        }
+        Some(v) => Ok(v),
+        None => Err(1),
+    }
+}
+
+#[early_panic(mode = "assume")]
+fn first_even(v: &[u64]) -> Option<u64> {
+    let x = *v.first()?;
+    if x % 2 == 1 {
+        return None;
+    }
+    Some(x)
+}
+
+#[test]
+fn test_tail_positions() {
+    assert_eq!(classify(Some(5)), Ok(5));
+    assert_eq!(classify(Some(150)), Ok(50));
+    assert_eq!(first_even(&[4]), Some(4));
+    let report = cvlr::rt::run_rule(
+        || {
+            let x = match cvlr::nondet::nondet_in(0u64..4) {
+                0 => None,
+                1 => Some(0),
+                2 => Some(250),
+                _ => Some(cvlr::nondet::nondet_in(1u64..200)),
+            };
+            assert!(classify(x).is_ok());
+            let v = [cvlr::nondet::nondet_in(0u64..10)];
+            assert_eq!(
+                first_even(&v[..cvlr::nondet::nondet_in(0usize..2)]).unwrap() % 2,
+                0
+            );
+        },
+        100,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+    assert!(report.discarded > 0);
+}
+
+#[early_panic(closures = false)]
+fn closure_errors_kept(v: &[u64]) -> Result<usize, u64> {
+    let errs = v
+        .iter()
+        .map(|x| if *x > 10 { Err(*x) } else { Ok(*x) })
+        .filter(|r| r.is_err())
+        .count();
+    Ok(errs)
+}
+
+#[early_panic]
+fn closure_try_cut(v: &[&str]) -> Result<u64, std::num::ParseIntError> {
+    let parse = |s: &str| -> Result<u64, std::num::ParseIntError> { Ok(s.parse::<u64>()?) };
+    Ok(v.iter().map(|s| parse(s).unwrap_or(0)).sum())
+}
+
+#[test]
+fn test_closures_not_rewritten_when_disabled() {
+    assert_eq!(closure_errors_kept(&[1, 20, 30]), Ok(2));
+}
+
+#[test]
+fn test_closures_rewritten_by_default() {
+    assert_eq!(closure_try_cut(&["1", "2"]), Ok(3));
+    assert!(std::panic::catch_unwind(|| closure_try_cut(&["x"])).is_err());
+}
@@ -2,3 +2,10 @@
 pub fn expand() {
     macrotest::expand("tests/expand/*.rs");
 }
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/expand/test_bad_mode.rs");
+    t.compile_fail("tests/expand/test_unsupported.rs");
+}
@@ -121,6 +121,18 @@ macro_rules! native_fixed {
             }
         }
 
+        impl<const F: u32> cvlr_nondet::NondetRange for $NativeFixed<F> {
+            fn nondet_in<R: core::ops::RangeBounds<Self>>(range: R) -> Self {
+                let val = NativeInt::nondet_in_bounds(
+                    range.start_bound().map(|b| b.val),
+                    range.end_bound().map(|b| b.val),
+                );
+                let v = Self::from_val(val);
+                cvlr_log::cvlr_log(cvlr_nondet::NONDET_IN_TAG, &v);
+                v
+            }
+        }
+
         impl<const F: u32, T: Into<NativeInt>> From<T> for $NativeFixed<F> {
             fn from(value: T) -> Self {
                 Self::new(value.into())
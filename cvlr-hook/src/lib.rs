@@ -1,6 +1,256 @@
+use cvlr_macro_utils::exits::ExitRewriter;
 use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, punctuated::Punctuated, ItemFn, Meta, Token};
+use syn::parse::{Parse, ParseStream};
+use syn::visit_mut::VisitMut;
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, Block, Expr, FnArg, Ident, ImplItem,
+    Item, ItemImpl, LitStr, Meta, Pat, ReturnType, Signature, Stmt, Token, Visibility,
+};
+
+/// Arguments of the hook attributes: the hook, then optional filters
+///
+/// - `name = "pattern"` only hooks functions whose name matches `pattern`, in
+///   which `*` matches any sequence of characters
+/// - `vis = "pub"` only hooks public functions (with any `pub` visibility, or in
+///   a trait impl)
+struct HookArgs {
+    hook: Expr,
+    name: Option<LitStr>,
+    pub_only: bool,
+}
+
+impl Parse for HookArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Err(input.error("Expected 1 argument"));
+        }
+        let hook: Expr = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+        let mut args = Self {
+            hook,
+            name: None,
+            pub_only: false,
+        };
+        for meta in metas {
+            let value = match &meta {
+                Meta::NameValue(nv) => match &nv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) => Some(s.clone()),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match value {
+                Some(s) if meta.path().is_ident("name") => args.name = Some(s),
+                Some(s) if meta.path().is_ident("vis") && s.value() == "pub" => {
+                    args.pub_only = true
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "Expected 1 argument, optionally followed by `name = \"...\"` or `vis = \"pub\"`",
+                    ))
+                }
+            }
+        }
+        Ok(args)
+    }
+}
+
+impl HookArgs {
+    /// Whether a function passes the filters
+    fn matches(&self, name: &Ident, is_pub: bool) -> bool {
+        if self.pub_only && !is_pub {
+            return false;
+        }
+        match &self.name {
+            Some(pattern) => glob_match(&pattern.value(), &name.to_string()),
+            None => true,
+        }
+    }
+
+    /// The hook expression for the function `name`
+    ///
+    /// A hook given as a path is called with the name of the function.
+    fn expr(&self, name: &Ident) -> Expr {
+        match &self.hook {
+            Expr::Path(path) => {
+                let name = name.to_string();
+                parse_quote! { #path(#name) }
+            }
+            hook => hook.clone(),
+        }
+    }
+}
+
+fn mentions_ident(tokens: TokenStream2, ident: &str) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => i == ident,
+        TokenTree::Group(g) => mentions_ident(g.stream(), ident),
+        _ => false,
+    })
+}
+
+/// `(&a, &b, ...)` for the parameters of `sig`, not including `self`
+fn args_tuple(sig: &Signature) -> syn::Result<Expr> {
+    let mut refs = Vec::new();
+    for input in &sig.inputs {
+        if let FnArg::Typed(pat_type) = input {
+            match &*pat_type.pat {
+                Pat::Ident(pat) => {
+                    let ident = &pat.ident;
+                    refs.push(quote!(&#ident));
+                }
+                pat => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "`args` requires every parameter to be an identifier",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(parse_quote! { ( #( #refs, )* ) })
+}
+
+/// Matches `name` against `pattern`, in which `*` matches any sequence of characters
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=name.len())
+                .filter(|i| name.is_char_boundary(*i))
+                .any(|i| glob_match(rest, &name[i..]))
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum HookKind {
+    Entry,
+    Exit,
+}
+
+/// Inserts the hook into every function of `item` that passes the filters
+///
+/// A function gets the hook directly. In an impl block or an inline module,
+/// every non-`const` function gets the hook, including those of nested impl
+/// blocks and inline modules.
+fn hook_item(item: Item, args: &HookArgs, kind: HookKind) -> syn::Result<Item> {
+    match item {
+        Item::Fn(mut item_fn) => {
+            let is_pub = !matches!(item_fn.vis, Visibility::Inherited);
+            hook_fn(&item_fn.sig, &mut item_fn.block, is_pub, args, kind)?;
+            Ok(Item::Fn(item_fn))
+        }
+        Item::Impl(mut item_impl) => {
+            hook_impl(&mut item_impl, args, kind)?;
+            Ok(Item::Impl(item_impl))
+        }
+        Item::Mod(mut item_mod) => match &mut item_mod.content {
+            Some((_, items)) => {
+                hook_mod_items(items, args, kind)?;
+                Ok(Item::Mod(item_mod))
+            }
+            None => Err(syn::Error::new_spanned(
+                item_mod,
+                "hooks can only be applied to inline modules",
+            )),
+        },
+        item => Err(syn::Error::new_spanned(
+            item,
+            "expected a function, an impl block, or an inline module",
+        )),
+    }
+}
+
+fn hook_mod_items(items: &mut [Item], args: &HookArgs, kind: HookKind) -> syn::Result<()> {
+    for item in items {
+        match item {
+            Item::Fn(item_fn) if item_fn.sig.constness.is_none() => {
+                let is_pub = !matches!(item_fn.vis, Visibility::Inherited);
+                hook_fn(&item_fn.sig, &mut item_fn.block, is_pub, args, kind)?;
+            }
+            Item::Impl(item_impl) => hook_impl(item_impl, args, kind)?,
+            Item::Mod(item_mod) => {
+                if let Some((_, items)) = &mut item_mod.content {
+                    hook_mod_items(items, args, kind)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn hook_impl(item_impl: &mut ItemImpl, args: &HookArgs, kind: HookKind) -> syn::Result<()> {
+    let is_trait_impl = item_impl.trait_.is_some();
+    for impl_item in &mut item_impl.items {
+        if let ImplItem::Fn(f) = impl_item {
+            if f.sig.constness.is_none() {
+                let is_pub = is_trait_impl || !matches!(f.vis, Visibility::Inherited);
+                hook_fn(&f.sig, &mut f.block, is_pub, args, kind)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn hook_fn(
+    sig: &Signature,
+    block: &mut Block,
+    is_pub: bool,
+    args: &HookArgs,
+    kind: HookKind,
+) -> syn::Result<()> {
+    if !args.matches(&sig.ident, is_pub) {
+        return Ok(());
+    }
+    let hook = args.expr(&sig.ident);
+    match kind {
+        HookKind::Entry => block.stmts.insert(0, hook_entry(sig, hook)?),
+        HookKind::Exit => hook_exit(&sig.output, block, parse_quote! { #hook; })?,
+    }
+    Ok(())
+}
+
+/// `let __entry = hook;`, with `args` bound to the parameters if the hook uses it
+fn hook_entry(sig: &Signature, hook: Expr) -> syn::Result<Stmt> {
+    let has_args_param = sig.inputs.iter().any(|input| match input {
+        FnArg::Typed(pat_type) => matches!(&*pat_type.pat, Pat::Ident(p) if p.ident == "args"),
+        FnArg::Receiver(_) => false,
+    });
+    if !has_args_param && mentions_ident(hook.to_token_stream(), "args") {
+        let tuple = args_tuple(sig)?;
+        Ok(parse_quote! {
+            let __entry = {
+                let args = #tuple;
+                #hook
+            };
+        })
+    } else {
+        Ok(parse_quote! { let __entry = #hook; })
+    }
+}
+
+fn expand(attr: TokenStream, input: TokenStream, kind: HookKind) -> TokenStream {
+    let args = parse_macro_input!(attr as HookArgs);
+    let item = parse_macro_input!(input as Item);
+    match hook_item(item, &args, kind) {
+        Ok(item) => item.into_token_stream().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
 
 /**
 * This macro is used to insert a hook at the start of a function.
@@ -14,90 +264,129 @@ use syn::{parse_macro_input, punctuated::Punctuated, ItemFn, Meta, Token};
   expands to
 
   fn t1() {
-      hook();
+      let __entry = hook();
       println!("t1");
   }
+
+* The value of the hook is bound to `__entry`, which an exit hook on the same
+* function can use. The hook can refer to the parameters of the function by
+* name, or to all of them with `args`, a tuple of references to the parameters
+* (not including `self`).
+* # Example
+* #[cvlr_hook_on_entry(snapshot(args))]
+  #[cvlr_hook_on_exit(check(&__entry, &__ret))]
+  fn transfer(from: &Account, to: &Account, amount: u64) -> u64 {
+      // let __entry = { let args = (&from, &to, &amount,); snapshot(args) };
+      // inserted here
+      ...
+  }
+
+* The attribute also applies to an impl block or an inline module, inserting
+* the hook into every function inside. A hook given as a path is called with
+* the name of the function (inside a module, the path is resolved from the
+* function, so `crate::` paths are the most robust), and `name = "pattern"`
+* or `vis = "pub"` restrict the functions that get the hook.
+* # Example
+* #[cvlr_hook_on_entry(on_entry, name = "process_*")]
+  impl Processor {
+      fn process_deposit(&mut self) {
+          // on_entry("process_deposit") inserted here
+      }
+      fn helper(&self) {}
+  }
 */
 #[allow(clippy::doc_overindented_list_items)]
 #[proc_macro_attribute]
 pub fn cvlr_hook_on_entry(attr: TokenStream, input: TokenStream) -> TokenStream {
-    // parse the attribute argument
-    let attr = parse_macro_input!(attr with Punctuated::<Meta, Token![,]>::parse_terminated);
-
-    if attr.len() != 1 {
-        return quote! {
-            compile_error!("Expected 1 argument");
-        }
-        .into();
-    }
-
-    let arg = attr.first().unwrap();
-
-    // parse the input tokens and make sure it is a function
-    let mut fn_item = parse_macro_input!(input as ItemFn);
-
-    // insert tokens_start into fn item statements at position 0
-    let tokens_start: syn::Stmt = syn::parse_quote! { #arg; };
-
-    fn_item.block.stmts.insert(0, tokens_start);
-
-    fn_item.into_token_stream().into()
+    expand(attr, input, HookKind::Entry)
 }
 
 /**
 * This macro is used to insert a hook at the end of a function.
-* If the function returns a value, the hook is inserted before the return statement.
+* The hook runs on every exit of the function: explicit `return`, `?`, and the
+* tail expression. The return value is bound to `__ret` before the hook runs,
+* so the hook can inspect it by reference.
+*
+* Exits inside closures, async blocks, and nested items are left alone. Exits
+* inside macro invocations cannot be redirected, and are a compile error.
 * # Example
 * #[cvlr_hook_on_exit(hook())]
-  fn t1() {
-      assert_eq!(1, 1);
-      assert_eq!(2, 2);
+  fn t1(x: u64) -> Option<u64> {
+      if x == 0 {
+          return None;
+      }
+      Some(x.checked_sub(1)?)
       // hook inserted here
   }
 
   expands to
 
-  fn t1() {
-      assert_eq!(1, 1);
-      assert_eq!(2, 2);
-      hook()
+  fn t1(x: u64) -> Option<u64> {
+      let __ret = '__cvlr_exit: {
+          if x == 0 {
+              break '__cvlr_exit None;
+          }
+          Some(match x.checked_sub(1) {
+              Some(v) => v,
+              None => break '__cvlr_exit None,
+          })
+      };
+      hook();
+      __ret
   }
+
+  and #[cvlr_hook_on_exit(check(&__ret))] passes the return value to `check`.
+  The value of an entry hook on the same function is available as `__entry`.
+
+* Like `cvlr_hook_on_entry`, the attribute also applies to impl blocks and
+* inline modules, and accepts the same filters.
 */
 #[allow(clippy::doc_overindented_list_items)]
 #[proc_macro_attribute]
 pub fn cvlr_hook_on_exit(attr: TokenStream, input: TokenStream) -> TokenStream {
-    // parse the attribute argument
-
-    let attr = parse_macro_input!(attr with Punctuated::<Meta, Token![,]>::parse_terminated);
-    if attr.len() != 1 {
-        return quote! {
-            compile_error!("Expected 1 argument");
-        }
-        .into();
-    }
+    expand(attr, input, HookKind::Exit)
+}
 
-    let arg = &attr.first().unwrap();
+fn is_entry_stmt(stmt: &Stmt) -> bool {
+    matches!(stmt, Stmt::Local(local) if matches!(&local.pat, Pat::Ident(p) if p.ident == "__entry"))
+}
 
-    // parse the input tokens and make sure it is a function
-    let mut fn_item = parse_macro_input!(input as ItemFn);
-    let ret_type = &fn_item.sig.output;
+/// Runs `hook` on every exit of `block`, with the return value bound to `__ret`
+///
+/// The value of an entry hook inserted before, `__entry`, stays in scope of
+/// the exit hook.
+fn hook_exit(ret_type: &ReturnType, block: &mut Block, hook: Stmt) -> syn::Result<()> {
+    let entry_len = block.stmts.iter().take_while(|s| is_entry_stmt(s)).count();
+    let mut stmts = block.stmts.split_off(entry_len);
+    let entry = std::mem::take(&mut block.stmts);
 
-    // create tokens_end
-    let stmt_end: syn::Stmt = syn::parse_quote! { #arg; };
+    let mut rewriter = ExitRewriter::new(
+        ret_type,
+        "'__cvlr_exit",
+        "cvlr_hook_on_exit cannot redirect `?` or `return` inside a macro invocation",
+    );
+    for stmt in &mut stmts {
+        rewriter.visit_stmt_mut(stmt);
+    }
+    if let Some(e) = rewriter.error {
+        return Err(e);
+    }
 
-    // len of fn item statements
-    let len = fn_item.block.stmts.len();
+    // -- the label is only needed if some exit breaks to it
+    let body = if rewriter.rewritten {
+        let label = &rewriter.label;
+        quote! { #label: { #( #stmts )* } }
+    } else {
+        quote! { { #( #stmts )* } }
+    };
 
-    match ret_type {
-        syn::ReturnType::Default => {
-            // insert tokens_end into fn item statements at position len
-            fn_item.block.stmts.insert(len, stmt_end);
-        }
-        syn::ReturnType::Type(_, _) => {
-            // insert tokens_end into fn item statements at position len-1
-            fn_item.block.stmts.insert(len - 1, stmt_end);
+    *block = parse_quote! {
+        {
+            #( #entry )*
+            let __ret = #body;
+            #hook
+            __ret
         }
-    }
-
-    fn_item.into_token_stream().into()
+    };
+    Ok(())
 }
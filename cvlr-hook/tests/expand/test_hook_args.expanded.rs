@@ -0,0 +1,20 @@
+use cvlr_hook::{cvlr_hook_on_entry, cvlr_hook_on_exit};
+fn snapshot(args: (&u64, &u64)) -> u64 {
+    *args.0 + *args.1
+}
+fn check(_sum: &u64, _ret: &u64) {}
+fn log_amount(_amount: &u64) {}
+fn add(a: u64, b: u64) -> u64 {
+    let __entry = {
+        let args = (&a, &b);
+        snapshot(args)
+    };
+    let __ret = { a + b };
+    check(&__entry, &__ret);
+    __ret
+}
+fn deposit(amount: u64) {
+    let __entry = log_amount(&amount);
+    let _ = amount;
+}
+fn main() {}
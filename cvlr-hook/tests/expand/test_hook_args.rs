@@ -0,0 +1,25 @@
+use cvlr_hook::{cvlr_hook_on_entry, cvlr_hook_on_exit};
+
+fn snapshot(args: (&u64, &u64)) -> u64 {
+    *args.0 + *args.1
+}
+
+fn check(_sum: &u64, _ret: &u64) {}
+
+fn log_amount(_amount: &u64) {}
+
+#[cvlr_hook_on_entry(snapshot(args))]
+#[cvlr_hook_on_exit(check(&__entry, &__ret))]
+fn add(a: u64, b: u64) -> u64 {
+    // let __entry = { let args = (&a, &b,); snapshot(args) }; inserted here
+    a + b
+    // check(&__entry, &__ret) inserted here
+}
+
+#[cvlr_hook_on_entry(log_amount(&amount))]
+fn deposit(amount: u64) {
+    // let __entry = log_amount(&amount); inserted here
+    let _ = amount;
+}
+
+fn main() {}
@@ -6,9 +6,10 @@ fn hook_end() {
     ();
 }
 fn tmp() -> Result<()> {
-    hook_start();
+    let __entry = hook_start();
+    let __ret = { Ok(()) };
     hook_end();
-    Ok(())
+    __ret
 }
 fn t3() {
     match (&tmp(), &Ok(())) {
@@ -26,24 +27,27 @@ fn t3() {
     };
 }
 fn t4() {
-    hook_start();
-    match (&1, &1) {
-        (left_val, right_val) => {
-            if !(*left_val == *right_val) {
-                let kind = ::core::panicking::AssertKind::Eq;
-                ::core::panicking::assert_failed(
-                    kind,
-                    &*left_val,
-                    &*right_val,
-                    ::core::option::Option::None,
-                );
+    let __entry = hook_start();
+    let __ret = {
+        match (&1, &1) {
+            (left_val, right_val) => {
+                if !(*left_val == *right_val) {
+                    let kind = ::core::panicking::AssertKind::Eq;
+                    ::core::panicking::assert_failed(
+                        kind,
+                        &*left_val,
+                        &*right_val,
+                        ::core::option::Option::None,
+                    );
+                }
             }
-        }
+        };
     };
     hook_end();
+    __ret
 }
 fn abs(x: i32) -> i32 {
-    hook_start();
+    let __entry = hook_start();
     if x >= 0 {
         {
             ::std::io::_print(format_args!("x is positive\n"));
@@ -57,16 +61,19 @@ fn abs(x: i32) -> i32 {
     }
 }
 fn abs2(x: i32) -> i32 {
+    let __ret = {
+        if x >= 0 {
+            {
+                ::std::io::_print(format_args!("x is positive\n"));
+            };
+            x
+        } else {
+            {
+                ::std::io::_print(format_args!("x is negative\n"));
+            };
+            -x
+        }
+    };
     hook_end();
-    if x >= 0 {
-        {
-            ::std::io::_print(format_args!("x is positive\n"));
-        };
-        x
-    } else {
-        {
-            ::std::io::_print(format_args!("x is negative\n"));
-        };
-        -x
-    }
+    __ret
 }
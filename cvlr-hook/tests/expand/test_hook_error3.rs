@@ -0,0 +1,13 @@
+use cvlr_hook::cvlr_hook_on_exit;
+
+fn hook() {
+    ();
+}
+
+#[cvlr_hook_on_exit(hook(), vis = "crate")]
+fn t1() {}
+
+#[cvlr_hook_on_exit(hook(), name = 1)]
+fn t2() {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use cvlr_hook::cvlr_hook_on_exit;
+fn hook() {
+    ();
+}
+fn main() {}
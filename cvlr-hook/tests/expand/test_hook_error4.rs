@@ -0,0 +1,13 @@
+use cvlr_hook::cvlr_hook_on_exit;
+
+fn hook() {
+    ();
+}
+
+#[cvlr_hook_on_exit(hook())]
+fn t1(x: Option<u64>) -> Option<u64> {
+    let v = vec![x?];
+    Some(v[0])
+}
+
+fn main() {}
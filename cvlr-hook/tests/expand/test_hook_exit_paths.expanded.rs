@@ -0,0 +1,56 @@
+use cvlr_hook::cvlr_hook_on_exit;
+fn hook() {
+    ();
+}
+fn check(_ret: &Option<u64>) {
+    ();
+}
+fn early_return(x: u64) -> u64 {
+    let __ret = '__cvlr_exit: {
+        if x == 0 {
+            break '__cvlr_exit 1;
+        }
+        x
+    };
+    hook();
+    __ret
+}
+fn question_mark(x: &str) -> Result<u64, std::num::ParseIntError> {
+    let __ret = '__cvlr_exit: {
+        let v = match x.parse::<u64>() {
+            ::core::result::Result::Ok(__cvlr_v) => __cvlr_v,
+            ::core::result::Result::Err(__cvlr_e) => {
+                break '__cvlr_exit (::core::result::Result::Err(
+                    ::core::convert::From::from(__cvlr_e),
+                ));
+            }
+        };
+        Ok(v)
+    };
+    hook();
+    __ret
+}
+fn with_ret(x: Option<u64>) -> Option<u64> {
+    let __ret = '__cvlr_exit: {
+        let v = match x {
+            ::core::option::Option::Some(__cvlr_v) => __cvlr_v,
+            ::core::option::Option::None => {
+                break '__cvlr_exit (::core::option::Option::None);
+            }
+        };
+        let f = |y: u64| -> Option<u64> { Some(y.checked_add(1)?) };
+        match v {
+            0 => None,
+            _ => f(v),
+        }
+    };
+    check(&__ret);
+    __ret
+}
+fn let_tail(x: u64) {
+    let __ret = {
+        let _y = x;
+    };
+    hook();
+    __ret
+}
@@ -0,0 +1,42 @@
+use cvlr_hook::cvlr_hook_on_exit;
+
+fn hook() {
+    ();
+}
+
+fn check(_ret: &Option<u64>) {
+    ();
+}
+
+#[cvlr_hook_on_exit(hook())]
+fn early_return(x: u64) -> u64 {
+    if x == 0 {
+        // hook inserted here
+        return 1;
+    }
+    // hook inserted here
+    x
+}
+
+#[cvlr_hook_on_exit(hook())]
+fn question_mark(x: &str) -> Result<u64, std::num::ParseIntError> {
+    // hook inserted here on error
+    let v = x.parse::<u64>()?;
+    Ok(v)
+}
+
+#[cvlr_hook_on_exit(check(&__ret))]
+fn with_ret(x: Option<u64>) -> Option<u64> {
+    let v = x?;
+    // -- exits of the closure are not exits of the function
+    let f = |y: u64| -> Option<u64> { Some(y.checked_add(1)?) };
+    match v {
+        0 => None,
+        _ => f(v),
+    }
+}
+
+#[cvlr_hook_on_exit(hook())]
+fn let_tail(x: u64) {
+    let _y = x;
+}
@@ -0,0 +1,33 @@
+use cvlr_hook::{cvlr_hook_on_entry, cvlr_hook_on_exit};
+fn on_entry(_name: &'static str) {}
+fn on_exit(_name: &'static str) {}
+struct Processor {
+    total: u64,
+}
+impl Processor {
+    pub fn process_deposit(&mut self, amount: u64) {
+        let __entry = on_entry("process_deposit");
+        let __ret = {
+            self.total += amount;
+        };
+        on_exit("process_deposit");
+        __ret
+    }
+    fn helper(&self) -> u64 {
+        self.total
+    }
+    const fn zero() -> u64 {
+        0
+    }
+}
+mod handlers {
+    pub fn initialize() {
+        let __entry = crate::on_entry("initialize");
+    }
+    mod nested {
+        fn close() {
+            let __entry = crate::on_entry("close");
+        }
+    }
+}
+fn main() {}
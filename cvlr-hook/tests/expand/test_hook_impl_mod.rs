@@ -0,0 +1,43 @@
+use cvlr_hook::{cvlr_hook_on_entry, cvlr_hook_on_exit};
+
+fn on_entry(_name: &'static str) {}
+
+fn on_exit(_name: &'static str) {}
+
+struct Processor {
+    total: u64,
+}
+
+#[cvlr_hook_on_entry(on_entry, name = "process_*")]
+#[cvlr_hook_on_exit(on_exit, vis = "pub")]
+impl Processor {
+    pub fn process_deposit(&mut self, amount: u64) {
+        // on_entry("process_deposit") inserted here
+        self.total += amount;
+        // on_exit("process_deposit") inserted here
+    }
+
+    fn helper(&self) -> u64 {
+        self.total
+    }
+
+    const fn zero() -> u64 {
+        0
+    }
+}
+
+// -- the hook path is resolved inside the module
+#[cvlr_hook_on_entry(crate::on_entry)]
+mod handlers {
+    pub fn initialize() {
+        // on_entry("initialize") inserted here
+    }
+
+    mod nested {
+        fn close() {
+            // on_entry("close") inserted here
+        }
+    }
+}
+
+fn main() {}
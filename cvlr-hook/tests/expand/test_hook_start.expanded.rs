@@ -3,7 +3,7 @@ fn hook() {
     ();
 }
 fn t1() {
-    hook();
+    let __entry = hook();
     {
         ::std::io::_print(format_args!("t1\n"));
     };
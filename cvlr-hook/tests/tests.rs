@@ -1,3 +1,226 @@
+use std::cell::{Cell, RefCell};
+
+use cvlr_hook::{cvlr_hook_on_entry, cvlr_hook_on_exit};
+
+std::thread_local! {
+    static EXITS: Cell<u32> = const { Cell::new(0) };
+    static RETS: RefCell<Vec<Option<u64>>> = const { RefCell::new(Vec::new()) };
+}
+
+fn on_exit() {
+    EXITS.with(|c| c.set(c.get() + 1));
+}
+
+fn exits() -> u32 {
+    EXITS.with(|c| c.replace(0))
+}
+
+fn record(ret: &Option<u64>) {
+    RETS.with(|r| r.borrow_mut().push(*ret));
+}
+
+#[cvlr_hook_on_exit(on_exit())]
+fn early_return(x: u64) -> u64 {
+    if x == 0 {
+        return 1;
+    }
+    for i in 0..x {
+        if i == 3 {
+            return i;
+        }
+    }
+    x
+}
+
+#[cvlr_hook_on_exit(on_exit())]
+fn question_mark(x: &str) -> Result<u64, String> {
+    let v = x.parse::<u64>().map_err(|e| e.to_string())?;
+    Ok(v)
+}
+
+#[cvlr_hook_on_exit(on_exit())]
+fn returning_match(x: Option<u64>) -> u64 {
+    match x {
+        Some(0) => return 0,
+        Some(v) => v,
+        None => 42,
+    }
+}
+
+#[cvlr_hook_on_exit(on_exit())]
+fn let_tail(x: u64) {
+    let _y = x;
+}
+
+#[cvlr_hook_on_exit(record(&__ret))]
+fn with_ret(x: Option<u64>) -> Option<u64> {
+    let v = x?;
+    let f = |y: u64| -> Option<u64> { Some(y.checked_sub(1)? * 2) };
+    f(v)
+}
+
+#[cvlr_hook_on_entry(on_exit())]
+#[cvlr_hook_on_exit(on_exit())]
+fn borrowed(v: &[u64]) -> &u64 {
+    if v.is_empty() {
+        return &0;
+    }
+    &v[0]
+}
+
+std::thread_local! {
+    static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn on_call(name: &'static str) {
+    CALLS.with(|c| c.borrow_mut().push(name));
+}
+
+fn calls() -> Vec<&'static str> {
+    CALLS.with(|c| std::mem::take(&mut *c.borrow_mut()))
+}
+
+struct Vault {
+    balance: u64,
+}
+
+#[cvlr_hook_on_entry(on_call, name = "process_*")]
+impl Vault {
+    fn process_deposit(&mut self, amount: u64) {
+        self.balance += amount;
+    }
+
+    fn process_withdraw(&mut self, amount: u64) -> Option<u64> {
+        self.balance = self.balance.checked_sub(amount)?;
+        Some(self.balance)
+    }
+
+    fn balance(&self) -> u64 {
+        self.balance
+    }
+}
+
+#[cvlr_hook_on_exit(crate::on_call, vis = "pub")]
+mod handlers {
+    pub fn initialize() -> u64 {
+        internal()
+    }
+
+    fn internal() -> u64 {
+        7
+    }
+
+    pub mod nested {
+        pub fn close(x: u64) -> Result<u64, String> {
+            if x == 0 {
+                return Err("empty".to_string());
+            }
+            Ok(x)
+        }
+    }
+}
+
+std::thread_local! {
+    static CHECKS: RefCell<Vec<(u64, u64)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn snapshot(args: (&&mut Vault, &u64)) -> u64 {
+    args.0.balance.saturating_add(*args.1)
+}
+
+fn check_balance(expected: &u64, ret: &Option<u64>) {
+    CHECKS.with(|c| c.borrow_mut().push((*expected, ret.unwrap_or(0))));
+}
+
+fn checks() -> Vec<(u64, u64)> {
+    CHECKS.with(|c| std::mem::take(&mut *c.borrow_mut()))
+}
+
+#[cvlr_hook_on_entry(snapshot(args))]
+#[cvlr_hook_on_exit(check_balance(&__entry, &__ret))]
+fn deposit(vault: &mut Vault, amount: u64) -> Option<u64> {
+    vault.balance = vault.balance.checked_add(amount)?;
+    Some(vault.balance)
+}
+
+// -- the exit hook is expanded first when listed first
+#[cvlr_hook_on_exit(check_balance(&__entry, &__ret))]
+#[cvlr_hook_on_entry(args.0.balance + *args.1)]
+fn deposit2(vault: &mut Vault, amount: u64) -> Option<u64> {
+    vault.balance += amount;
+    Some(vault.balance)
+}
+
+#[cvlr_hook_on_entry(on_call(if *amount > 0 { "positive" } else { "zero" }))]
+fn named_arg(amount: &u64) -> u64 {
+    *amount
+}
+
+#[test]
+fn hooks_with_args() {
+    let mut vault = Vault { balance: 1 };
+    assert_eq!(deposit(&mut vault, 2), Some(3));
+    assert_eq!(deposit(&mut vault, u64::MAX), None);
+    assert_eq!(deposit2(&mut vault, 4), Some(7));
+    assert_eq!(checks(), [(3, 3), (u64::MAX, 0), (7, 7)]);
+
+    assert_eq!(named_arg(&0), 0);
+    assert_eq!(named_arg(&5), 5);
+    assert_eq!(calls(), ["zero", "positive"]);
+}
+
+#[test]
+fn hooks_on_impl_blocks() {
+    let mut vault = Vault { balance: 0 };
+    vault.process_deposit(5);
+    assert_eq!(vault.process_withdraw(10), None);
+    assert_eq!(vault.balance(), 5);
+    assert_eq!(calls(), ["process_deposit", "process_withdraw"]);
+}
+
+#[test]
+fn hooks_on_modules() {
+    assert_eq!(handlers::initialize(), 7);
+    assert!(handlers::nested::close(0).is_err());
+    assert_eq!(handlers::nested::close(3), Ok(3));
+    assert_eq!(calls(), ["initialize", "close", "close"]);
+}
+
+#[test]
+fn exit_hook_on_every_path() {
+    assert_eq!(early_return(0), 1);
+    assert_eq!(exits(), 1);
+    assert_eq!(early_return(5), 3);
+    assert_eq!(exits(), 1);
+    assert_eq!(early_return(2), 2);
+    assert_eq!(exits(), 1);
+
+    assert_eq!(question_mark("7"), Ok(7));
+    assert_eq!(exits(), 1);
+    assert!(question_mark("x").is_err());
+    assert_eq!(exits(), 1);
+
+    assert_eq!(returning_match(Some(0)), 0);
+    assert_eq!(returning_match(Some(3)), 3);
+    assert_eq!(returning_match(None), 42);
+    assert_eq!(exits(), 3);
+
+    let_tail(1);
+    assert_eq!(exits(), 1);
+
+    assert_eq!(*borrowed(&[4, 5]), 4);
+    assert_eq!(*borrowed(&[]), 0);
+    assert_eq!(exits(), 4);
+}
+
+#[test]
+fn exit_hook_sees_return_value() {
+    assert_eq!(with_ret(None), None);
+    assert_eq!(with_ret(Some(0)), None);
+    assert_eq!(with_ret(Some(5)), Some(8));
+    RETS.with(|r| assert_eq!(*r.borrow(), vec![None, None, Some(8)]));
+}
+
 #[test]
 pub fn pass() {
     macrotest::expand("tests/expand/*.rs");
@@ -8,4 +231,6 @@ fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/expand/test_hook_error.rs");
     t.compile_fail("tests/expand/test_hook_error2.rs");
+    t.compile_fail("tests/expand/test_hook_error3.rs");
+    t.compile_fail("tests/expand/test_hook_error4.rs");
 }
@@ -31,40 +31,121 @@ pub mod rt_decls {
 #[cfg(feature = "rt")]
 #[allow(improper_ctypes_definitions)]
 mod rt_impls {
+    use crate::rt::{record, scope_end, scope_start, TraceEvent};
+    use std::{string::ToString, vec};
+
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_tag(_tag: &str) {}
+    pub extern "C" fn CVT_calltrace_print_tag(tag: &str) {
+        record(TraceEvent::Tag(tag.to_string()));
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u64_1(_tag: &str, _x: u64) {}
+    pub extern "C" fn CVT_calltrace_print_u64_1(tag: &str, x: u64) {
+        record(TraceEvent::U64 {
+            tag: tag.to_string(),
+            values: vec![x],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u64_2(_tag: &str, _x: u64, _y: u64) {}
+    pub extern "C" fn CVT_calltrace_print_u64_2(tag: &str, x: u64, y: u64) {
+        record(TraceEvent::U64 {
+            tag: tag.to_string(),
+            values: vec![x, y],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u64_3(_tag: &str, _x: u64, _y: u64, _z: u64) {}
+    pub extern "C" fn CVT_calltrace_print_u64_3(tag: &str, x: u64, y: u64, z: u64) {
+        record(TraceEvent::U64 {
+            tag: tag.to_string(),
+            values: vec![x, y, z],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u128(_tag: &str, _x: u128) {}
+    pub extern "C" fn CVT_calltrace_print_u128(tag: &str, x: u128) {
+        record(TraceEvent::U128 {
+            tag: tag.to_string(),
+            value: x,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_i64_1(_tag: &str, _x: i64) {}
+    pub extern "C" fn CVT_calltrace_print_i64_1(tag: &str, x: i64) {
+        record(TraceEvent::I64 {
+            tag: tag.to_string(),
+            values: vec![x],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_i64_2(_tag: &str, _x: i64, _y: i64) {}
+    pub extern "C" fn CVT_calltrace_print_i64_2(tag: &str, x: i64, y: i64) {
+        record(TraceEvent::I64 {
+            tag: tag.to_string(),
+            values: vec![x, y],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_i64_3(_tag: &str, _x: i64, _y: i64, _z: i64) {}
+    pub extern "C" fn CVT_calltrace_print_i64_3(tag: &str, x: i64, y: i64, z: i64) {
+        record(TraceEvent::I64 {
+            tag: tag.to_string(),
+            values: vec![x, y, z],
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_i128(_tag: &str, _x: i128) {}
+    pub extern "C" fn CVT_calltrace_print_i128(tag: &str, x: i128) {
+        record(TraceEvent::I128 {
+            tag: tag.to_string(),
+            value: x,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u64_as_fixed(_tag: &str, _x: u64, _y: u64) {}
+    pub extern "C" fn CVT_calltrace_print_u64_as_fixed(tag: &str, x: u64, y: u64) {
+        record(TraceEvent::Fixed {
+            tag: tag.to_string(),
+            value: x,
+            bits: y,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_u64_as_decimal(_tag: &str, _x: u64, _y: u64) {}
+    pub extern "C" fn CVT_calltrace_print_u64_as_decimal(tag: &str, x: u64, y: u64) {
+        record(TraceEvent::Decimal {
+            tag: tag.to_string(),
+            value: x,
+            decimals: y,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_string(_tag: &str, _v: &str) {}
+    pub extern "C" fn CVT_calltrace_print_string(tag: &str, v: &str) {
+        record(TraceEvent::Str {
+            tag: tag.to_string(),
+            value: v.to_string(),
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_print_location(_file: &str, _line: u64) {}
+    pub extern "C" fn CVT_calltrace_print_location(file: &str, line: u64) {
+        record(TraceEvent::Location {
+            file: file.to_string(),
+            line,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_attach_location(_file: &str, _line: u64) {}
+    pub extern "C" fn CVT_calltrace_attach_location(file: &str, line: u64) {
+        record(TraceEvent::AttachLocation {
+            file: file.to_string(),
+            line,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_rule_location(_file: &str, _line: u64) {}
+    pub extern "C" fn CVT_rule_location(file: &str, line: u64) {
+        record(TraceEvent::RuleLocation {
+            file: file.to_string(),
+            line,
+        });
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_scope_start(_name: &str) {}
+    pub extern "C" fn CVT_calltrace_scope_start(name: &str) {
+        scope_start(name);
+    }
     #[no_mangle]
-    pub extern "C" fn CVT_calltrace_scope_end(_name: &str) {}
+    pub extern "C" fn CVT_calltrace_scope_end(name: &str) {
+        scope_end(name);
+    }
 }
 pub use rt_decls::*;
 
@@ -1,8 +1,14 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod core;
 mod cvt_macros;
 mod log;
 
+#[cfg(feature = "rt")]
+pub mod rt;
+
 pub use crate::core::*;
 pub use crate::log::*;
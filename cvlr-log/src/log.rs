@@ -185,6 +185,28 @@ impl<T: CvlrLog, E: CvlrLog> CvlrLog for Result<T, E> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: CvlrLog> CvlrLog for std::vec::Vec<T> {
+    fn log(&self, tag: &str, logger: &mut CvlrLogger) {
+        logger.log_scope_start(tag);
+        logger.log_u64("len", self.len() as u64);
+        for v in self.iter() {
+            v.log("elem", logger);
+        }
+        logger.log_scope_end(tag);
+    }
+}
+
+#[cfg(feature = "std")]
+impl CvlrLog for std::string::String {
+    fn log(&self, tag: &str, logger: &mut CvlrLogger) {
+        logger.log_scope_start(tag);
+        logger.log_u64("len", self.len() as u64);
+        logger.log_str("contents", self.as_str());
+        logger.log_scope_end(tag);
+    }
+}
+
 #[cfg(feature = "mathint")]
 impl CvlrLog for cvlr_mathint::NativeInt {
     #[inline(always)]
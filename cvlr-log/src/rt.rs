@@ -0,0 +1,313 @@
+//! Recording of calltraces under the `rt` feature
+//!
+//! The `rt` implementations of the calltrace intrinsics record every event
+//! into a per-thread [Trace], so that tests can inspect what a spec logged.
+//!
+//! Checks of cvlr-asserts are recorded once the recorder is first used, by
+//! any calltrace intrinsic or by [take_trace] and [clear_trace]. Clear the
+//! trace before a run, as `cvlr::rt::run_rule` does, to record all its checks.
+
+mod json;
+
+use std::{
+    cell::RefCell,
+    fmt,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// A single entry of a calltrace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// A tag without a value
+    Tag(String),
+    U64 {
+        tag: String,
+        values: Vec<u64>,
+    },
+    I64 {
+        tag: String,
+        values: Vec<i64>,
+    },
+    U128 {
+        tag: String,
+        value: u128,
+    },
+    I128 {
+        tag: String,
+        value: i128,
+    },
+    Str {
+        tag: String,
+        value: String,
+    },
+    /// A fixed-point number with `bits` fractional bits
+    Fixed {
+        tag: String,
+        value: u64,
+        bits: u64,
+    },
+    /// A decimal number with `decimals` fractional digits
+    Decimal {
+        tag: String,
+        value: u64,
+        decimals: u64,
+    },
+    Location {
+        file: String,
+        line: u64,
+    },
+    /// Location attached to the next assert
+    AttachLocation {
+        file: String,
+        line: u64,
+    },
+    RuleLocation {
+        file: String,
+        line: u64,
+    },
+    /// Outcome of an `assert`, `assume`, `satisfy`, or `sanity` check
+    Check {
+        kind: String,
+        holds: bool,
+    },
+    Scope {
+        name: String,
+        events: Vec<TraceEvent>,
+    },
+}
+
+/// Name of the scope that holds the description of the next assert
+///
+/// Duplicated from cvlr-asserts, which prints descriptions as tags in it.
+pub const DESC_SCOPE: &str = "desc";
+
+impl TraceEvent {
+    /// The description held by a [DESC_SCOPE] scope
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            TraceEvent::Scope { name, events } if name == DESC_SCOPE => match events.as_slice() {
+                [TraceEvent::Tag(desc)] => Some(desc),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A recorded calltrace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    root: Vec<TraceEvent>,
+    /// Open scopes, innermost last
+    scopes: Vec<(String, Vec<TraceEvent>)>,
+}
+
+impl Recorder {
+    fn current(&mut self) -> &mut Vec<TraceEvent> {
+        match self.scopes.last_mut() {
+            Some((_, events)) => events,
+            None => &mut self.root,
+        }
+    }
+
+    fn close_scope(&mut self) {
+        if let Some((name, events)) = self.scopes.pop() {
+            self.current().push(TraceEvent::Scope { name, events });
+        }
+    }
+
+    fn take(&mut self) -> Trace {
+        while !self.scopes.is_empty() {
+            self.close_scope();
+        }
+        Trace {
+            events: std::mem::take(&mut self.root),
+        }
+    }
+}
+
+std::thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+}
+
+/// Runs `f` on the recorder of this thread
+///
+/// The first use installs the recorder in cvlr-asserts, so that checks are
+/// recorded from then on.
+fn with_recorder<R>(f: impl FnOnce(&mut Recorder) -> R) -> R {
+    cvlr_asserts::__private::set_calltrace(cvlr_asserts::__private::Calltrace {
+        attach_location: |file, line| {
+            record(TraceEvent::AttachLocation {
+                file: file.to_string(),
+                line,
+            })
+        },
+        print_tag: |tag| record(TraceEvent::Tag(tag.to_string())),
+        scope_start,
+        scope_end,
+        record_check: |kind, holds| {
+            record(TraceEvent::Check {
+                kind: kind.to_string(),
+                holds,
+            })
+        },
+    });
+    RECORDER.with(|r| f(&mut r.borrow_mut()))
+}
+
+pub(crate) fn record(event: TraceEvent) {
+    with_recorder(|r| r.current().push(event));
+}
+
+pub(crate) fn scope_start(name: &str) {
+    with_recorder(|r| r.scopes.push((name.to_string(), Vec::new())));
+}
+
+/// Closes the innermost scope; an unmatched end is ignored
+pub(crate) fn scope_end(_name: &str) {
+    with_recorder(|r| r.close_scope());
+}
+
+/// Take the trace recorded so far on this thread, closing any open scopes
+pub fn take_trace() -> Trace {
+    with_recorder(|r| r.take())
+}
+
+/// Discard the trace recorded so far on this thread
+pub fn clear_trace() {
+    let _ = take_trace();
+}
+
+/// Guard that prints the trace of this thread to stderr if dropped while panicking
+///
+/// # Example
+///
+/// ```
+/// let _guard = cvlr::log::rt::print_trace_on_failure();
+/// cvlr::clog!(42u64 => "x");
+/// ```
+pub fn print_trace_on_failure() -> PrintTraceOnFailure {
+    PrintTraceOnFailure { _priv: () }
+}
+
+/// See [print_trace_on_failure]
+pub struct PrintTraceOnFailure {
+    _priv: (),
+}
+
+impl Drop for PrintTraceOnFailure {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            std::eprintln!("calltrace:\n{}", take_trace());
+        }
+    }
+}
+
+/// Renders a decimal number with `decimals` fractional digits
+///
+/// A scale that does not fit in a `u64` is rendered as `value e-decimals`.
+fn decimal_string(value: u64, decimals: u64) -> String {
+    if decimals == 0 {
+        return value.to_string();
+    }
+    let scale = u32::try_from(decimals)
+        .ok()
+        .and_then(|d| 10u64.checked_pow(d));
+    match scale {
+        Some(scale) => std::format!(
+            "{}.{:0width$}",
+            value / scale,
+            value % scale,
+            width = decimals as usize
+        ),
+        None => std::format!("{}e-{}", value, decimals),
+    }
+}
+
+/// Renders a fixed-point number with `bits` fractional bits
+fn fixed_string(value: u64, bits: u64) -> String {
+    (value as f64 / 2f64.powi(bits as i32)).to_string()
+}
+
+fn fmt_events(f: &mut fmt::Formatter<'_>, events: &[TraceEvent], depth: usize) -> fmt::Result {
+    for event in events {
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        if let Some(desc) = event.description() {
+            writeln!(f, "desc: {:?}", desc)?;
+            continue;
+        }
+        match event {
+            TraceEvent::Tag(tag) => writeln!(f, "{}", tag)?,
+            TraceEvent::U64 { tag, values } if values.len() == 1 => {
+                writeln!(f, "{}: {}", tag, values[0])?
+            }
+            TraceEvent::U64 { tag, values } => writeln!(f, "{}: {:?}", tag, values)?,
+            TraceEvent::I64 { tag, values } if values.len() == 1 => {
+                writeln!(f, "{}: {}", tag, values[0])?
+            }
+            TraceEvent::I64 { tag, values } => writeln!(f, "{}: {:?}", tag, values)?,
+            TraceEvent::U128 { tag, value } => writeln!(f, "{}: {}", tag, value)?,
+            TraceEvent::I128 { tag, value } => writeln!(f, "{}: {}", tag, value)?,
+            TraceEvent::Str { tag, value } => writeln!(f, "{}: {:?}", tag, value)?,
+            TraceEvent::Fixed { tag, value, bits } => writeln!(
+                f,
+                "{}: {} ({} >> {})",
+                tag,
+                fixed_string(*value, *bits),
+                value,
+                bits
+            )?,
+            TraceEvent::Decimal {
+                tag,
+                value,
+                decimals,
+            } => writeln!(f, "{}: {}", tag, decimal_string(*value, *decimals))?,
+            TraceEvent::Location { file, line } => writeln!(f, "@ {}:{}", file, line)?,
+            TraceEvent::AttachLocation { file, line } => writeln!(f, "at {}:{}", file, line)?,
+            TraceEvent::RuleLocation { file, line } => writeln!(f, "rule {}:{}", file, line)?,
+            TraceEvent::Check { kind, holds } => {
+                writeln!(f, "{} {}", kind, if *holds { "holds" } else { "FAILED" })?
+            }
+            TraceEvent::Scope { name, events } => {
+                writeln!(f, "{} {{", name)?;
+                fmt_events(f, events, depth + 1)?;
+                writeln!(f, "{:indent$}}}", "", indent = depth * 2)?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prints the trace as an indented tree, one event per line
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_events(f, &self.events, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_string() {
+        assert_eq!(decimal_string(7, 0), "7");
+        assert_eq!(decimal_string(12345, 2), "123.45");
+        assert_eq!(decimal_string(5, 3), "0.005");
+        assert_eq!(decimal_string(7, 19), "0.0000000000000000007");
+        assert_eq!(decimal_string(7, 20), "7e-20");
+        assert_eq!(decimal_string(7, u64::MAX), "7e-18446744073709551615");
+    }
+}
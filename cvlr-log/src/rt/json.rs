@@ -0,0 +1,302 @@
+//! JSON export of recorded calltraces
+//!
+//! The document has the shape of the prover's rule output, so that concrete
+//! and symbolic traces can be shown by the same viewer. The calltrace is a
+//! tree of nodes, each with a `message`, a `type`, a `status`, a
+//! `jumpToDefinition` location (or `null`), and a `childrenList`:
+//!
+//! ```json
+//! {
+//!   "status": "VIOLATED",
+//!   "jumpToDefinition": { "file": "src/certora/spec.rs", "line": 10 },
+//!   "assertMessage": ["x is eight"],
+//!   "callTrace": {
+//!     "message": "rule", "type": "ScopeInstance", "status": "VIOLATED",
+//!     "jumpToDefinition": { "file": "src/certora/spec.rs", "line": 10 },
+//!     "childrenList": [
+//!       { "message": "inputs", "type": "ScopeInstance", "status": "NONE",
+//!         "jumpToDefinition": null, "childrenList": [
+//!         { "message": "x: 7", "type": "LabelInstance", "status": "NONE",
+//!           "jumpToDefinition": null, "childrenList": [] }
+//!       ] },
+//!       { "message": "x is eight", "type": "AssertInstance", "status": "VIOLATED",
+//!         "jumpToDefinition": { "file": "src/certora/spec.rs", "line": 12 },
+//!         "childrenList": [] }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Scopes are `ScopeInstance` nodes, and values, tags, and locations are
+//! `LabelInstance` nodes whose message is rendered as in the text form of the
+//! trace. Checks are `AssertInstance`, `AssumeInstance`, `SatisfyInstance`, or
+//! `SanityInstance` nodes, with status `VERIFIED` or `VIOLATED`, and take
+//! their message from the description and their location from the location
+//! attached to them. The rule is `VIOLATED` if an assert, satisfy, or sanity
+//! check failed, `SANITY_FAILED` if the run was discarded by an assume, since
+//! no assertion was reached, and `VERIFIED` otherwise.
+
+use std::{
+    io,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{Trace, TraceEvent};
+
+/// Minimal JSON writer
+struct Json {
+    out: String,
+    /// Whether the current object or array has an element already
+    has_elem: Vec<bool>,
+}
+
+impl Json {
+    fn new() -> Self {
+        Self {
+            out: String::new(),
+            has_elem: Vec::new(),
+        }
+    }
+
+    fn sep(&mut self) {
+        if let Some(has) = self.has_elem.last_mut() {
+            if *has {
+                self.out.push(',');
+            }
+            *has = true;
+        }
+    }
+
+    fn open(&mut self, c: char) {
+        self.sep();
+        self.out.push(c);
+        self.has_elem.push(false);
+    }
+
+    fn close(&mut self, c: char) {
+        self.has_elem.pop();
+        self.out.push(c);
+    }
+
+    fn key(&mut self, k: &str) {
+        self.sep();
+        self.string_raw(k);
+        self.out.push(':');
+        // -- the value that follows is not a new element
+        if let Some(has) = self.has_elem.last_mut() {
+            *has = false;
+        }
+    }
+
+    fn string_raw(&mut self, s: &str) {
+        self.out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.out.push_str("\\\""),
+                '\\' => self.out.push_str("\\\\"),
+                '\n' => self.out.push_str("\\n"),
+                '\r' => self.out.push_str("\\r"),
+                '\t' => self.out.push_str("\\t"),
+                c if (c as u32) < 0x20 => {
+                    self.out.push_str(&std::format!("\\u{:04x}", c as u32));
+                }
+                c => self.out.push(c),
+            }
+        }
+        self.out.push('"');
+    }
+
+    fn string(&mut self, s: &str) {
+        self.sep();
+        self.string_raw(s);
+    }
+
+    fn raw(&mut self, s: &str) {
+        self.sep();
+        self.out.push_str(s);
+    }
+
+    fn field_str(&mut self, k: &str, v: &str) {
+        self.key(k);
+        self.string(v);
+    }
+
+    fn field_raw(&mut self, k: &str, v: &str) {
+        self.key(k);
+        self.raw(v);
+    }
+
+    fn location(&mut self, file: &str, line: u64) {
+        self.open('{');
+        self.field_str("file", file);
+        self.field_raw("line", &line.to_string());
+        self.close('}');
+    }
+}
+
+fn location(json: &mut Json, location: Option<(&str, u64)>) {
+    json.key("jumpToDefinition");
+    match location {
+        Some((file, line)) => json.location(file, line),
+        None => json.raw("null"),
+    }
+}
+
+/// A node without children
+fn leaf(json: &mut Json, message: &str, ty: &str, status: &str, loc: Option<(&str, u64)>) {
+    json.open('{');
+    json.field_str("message", message);
+    json.field_str("type", ty);
+    json.field_str("status", status);
+    location(json, loc);
+    json.key("childrenList");
+    json.open('[');
+    json.close(']');
+    json.close('}');
+}
+
+fn check_type(kind: &str) -> &'static str {
+    match kind {
+        "assume" => "AssumeInstance",
+        "satisfy" => "SatisfyInstance",
+        "sanity" => "SanityInstance",
+        _ => "AssertInstance",
+    }
+}
+
+fn children(json: &mut Json, events: &[TraceEvent]) {
+    let mut attached: Option<(&str, u64)> = None;
+    let mut description: Option<&str> = None;
+    json.key("childrenList");
+    json.open('[');
+    for event in events {
+        if let Some(desc) = event.description() {
+            description = Some(desc);
+            continue;
+        }
+        match event {
+            TraceEvent::AttachLocation { file, line } => attached = Some((file, *line)),
+            // -- reported once, on the root node
+            TraceEvent::RuleLocation { .. } => {}
+            TraceEvent::Location { file, line } => {
+                let message = std::format!("{}:{}", file, line);
+                leaf(json, &message, "LabelInstance", "NONE", Some((file, *line)));
+            }
+            TraceEvent::Check { kind, holds } => {
+                let status = if *holds { "VERIFIED" } else { "VIOLATED" };
+                let message = description.take().unwrap_or(kind);
+                leaf(json, message, check_type(kind), status, attached.take());
+            }
+            TraceEvent::Scope {
+                name,
+                events: nested,
+            } => {
+                json.open('{');
+                json.field_str("message", name);
+                json.field_str("type", "ScopeInstance");
+                json.field_str("status", "NONE");
+                location(json, None);
+                children(json, nested);
+                json.close('}');
+            }
+            _ => leaf(json, &label(event), "LabelInstance", "NONE", None),
+        }
+    }
+    json.close(']');
+}
+
+/// Message of a value or tag, the line of the event in the text form of the trace
+fn label(event: &TraceEvent) -> String {
+    let trace = Trace {
+        events: std::vec![event.clone()],
+    };
+    trace.to_string().trim_end().to_string()
+}
+
+fn failed_descriptions<'a>(events: &'a [TraceEvent], out: &mut Vec<&'a str>) {
+    let mut description = None;
+    for event in events {
+        if let Some(desc) = event.description() {
+            description = Some(desc);
+            continue;
+        }
+        match event {
+            TraceEvent::Check { holds, .. } => {
+                if !holds {
+                    out.extend(description);
+                }
+                description = None;
+            }
+            TraceEvent::Scope { events, .. } => failed_descriptions(events, out),
+            _ => {}
+        }
+    }
+}
+
+fn rule_location(events: &[TraceEvent]) -> Option<(&str, u64)> {
+    events.iter().find_map(|e| match e {
+        TraceEvent::RuleLocation { file, line } => Some((file.as_str(), *line)),
+        TraceEvent::Scope { events, .. } => rule_location(events),
+        _ => None,
+    })
+}
+
+fn failed_check(events: &[TraceEvent], kinds: &[&str]) -> bool {
+    events.iter().any(|e| match e {
+        TraceEvent::Check { kind, holds } => !holds && kinds.contains(&kind.as_str()),
+        TraceEvent::Scope { events, .. } => failed_check(events, kinds),
+        _ => false,
+    })
+}
+
+impl Trace {
+    /// Outcome of the recorded run: `violated`, `discarded`, or `passed`
+    pub fn status(&self) -> &'static str {
+        if failed_check(&self.events, &["assert", "satisfy", "sanity"]) {
+            "violated"
+        } else if failed_check(&self.events, &["assume"]) {
+            "discarded"
+        } else {
+            "passed"
+        }
+    }
+
+    /// Serializes the trace to a JSON document, described in the module docs
+    pub fn to_json(&self) -> String {
+        let status = match self.status() {
+            "violated" => "VIOLATED",
+            "discarded" => "SANITY_FAILED",
+            _ => "VERIFIED",
+        };
+        let rule = rule_location(&self.events);
+        let mut messages = Vec::new();
+        failed_descriptions(&self.events, &mut messages);
+
+        let mut json = Json::new();
+        json.open('{');
+        json.field_str("status", status);
+        location(&mut json, rule);
+        json.key("assertMessage");
+        json.open('[');
+        for message in messages {
+            json.string(message);
+        }
+        json.close(']');
+        json.key("callTrace");
+        json.open('{');
+        json.field_str("message", "rule");
+        json.field_str("type", "ScopeInstance");
+        json.field_str("status", status);
+        location(&mut json, rule);
+        children(&mut json, &self.events);
+        json.close('}');
+        json.close('}');
+        json.out
+    }
+
+    /// Writes the JSON document of [Trace::to_json] to `w`
+    pub fn write_json<W: io::Write>(&self, mut w: W) -> io::Result<()> {
+        w.write_all(self.to_json().as_bytes())
+    }
+}
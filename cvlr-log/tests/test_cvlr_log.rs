@@ -170,3 +170,13 @@ fn test_cvlr_log_nested_results() {
     let nested_err: Result<Result<u64, &str>, &str> = Ok(Err("inner error"));
     cvlr_log!(nested_err => "nested_err");
 }
+
+#[test]
+fn test_cvlr_log_collections() {
+    // Test vectors and strings
+    let v: Vec<u64> = vec![1, 2, 3];
+    let s = String::from("name");
+    cvlr_log!(v => "vec");
+    cvlr_log!(s => "string");
+    cvlr_log!(v, s);
+}
@@ -0,0 +1,186 @@
+use cvlr::log::rt::{clear_trace, take_trace, TraceEvent};
+use cvlr::log::{cvlr_log, log_scope_end, log_scope_start, CvlrLogger};
+
+fn u64_event(tag: &str, v: u64) -> TraceEvent {
+    TraceEvent::U64 {
+        tag: tag.to_string(),
+        values: vec![v],
+    }
+}
+
+#[test]
+fn test_trace_records_values() {
+    clear_trace();
+    cvlr_log!(42u64 => "answer");
+    cvlr_log!(-1i32 => "neg");
+    cvlr_log!("hello" => "greeting");
+    let mut logger = CvlrLogger::new();
+    logger.log_str("name", "cvlr");
+    logger.log_u64_as_dec("amount", 12345, 2);
+
+    let trace = take_trace();
+    assert_eq!(
+        trace.events,
+        vec![
+            u64_event("answer", 42),
+            TraceEvent::I64 {
+                tag: "neg".to_string(),
+                values: vec![-1],
+            },
+            TraceEvent::Tag("hello".to_string()),
+            TraceEvent::Str {
+                tag: "name".to_string(),
+                value: "cvlr".to_string(),
+            },
+            TraceEvent::Decimal {
+                tag: "amount".to_string(),
+                value: 12345,
+                decimals: 2,
+            },
+        ]
+    );
+    assert!(take_trace().is_empty());
+}
+
+#[test]
+fn test_trace_scopes() {
+    clear_trace();
+    log_scope_start("outer");
+    cvlr_log!(1u64 => "a");
+    log_scope_start("inner");
+    cvlr_log!(2u64 => "b");
+    log_scope_end("inner");
+    log_scope_end("outer");
+    // -- unmatched end is ignored
+    log_scope_end("outer");
+    // -- open scopes are closed by take_trace
+    log_scope_start("open");
+
+    let trace = take_trace();
+    assert_eq!(
+        trace.events,
+        vec![
+            TraceEvent::Scope {
+                name: "outer".to_string(),
+                events: vec![
+                    u64_event("a", 1),
+                    TraceEvent::Scope {
+                        name: "inner".to_string(),
+                        events: vec![u64_event("b", 2)],
+                    },
+                ],
+            },
+            TraceEvent::Scope {
+                name: "open".to_string(),
+                events: vec![],
+            },
+        ]
+    );
+    assert_eq!(
+        trace.to_string(),
+        "outer {\n  a: 1\n  inner {\n    b: 2\n  }\n}\nopen {\n}\n"
+    );
+}
+
+#[test]
+fn test_trace_assert_description() {
+    clear_trace();
+    cvlr::asserts::cvlr_assert!(true, "always holds");
+    let trace = take_trace();
+    assert_eq!(
+        trace.events[trace.events.len() - 2..],
+        [
+            TraceEvent::Scope {
+                name: "desc".to_string(),
+                events: vec![TraceEvent::Tag("always holds".to_string())],
+            },
+            TraceEvent::Check {
+                kind: "assert".to_string(),
+                holds: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_trace_display() {
+    clear_trace();
+    let mut logger = CvlrLogger::new();
+    logger.log_u64_2("pair", 1, 2);
+    logger.log_u64_as_dec("amount", 5, 3);
+    logger.log_u64_as_fp("price", 3 << 15, 16);
+    assert_eq!(
+        take_trace().to_string(),
+        "pair: [1, 2]\namount: 0.005\nprice: 1.5 (98304 >> 16)\n"
+    );
+}
+
+/// Drops the whitespace outside of strings, so that the compact output of
+/// `to_json` compares equal to an indented fixture
+fn compact_json(s: &str) -> String {
+    let mut out = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in s.chars() {
+        if in_string {
+            out.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+            out.push(c);
+        } else if !c.is_whitespace() {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_trace_json() {
+    clear_trace();
+    cvlr::log::log_rule_location("spec.rs", 10);
+    log_scope_start("inputs");
+    cvlr_log!(7u64 => "x");
+    cvlr_log!(u128::MAX => "big");
+    log_scope_end("inputs");
+    let mut logger = CvlrLogger::new();
+    logger.log_u64_as_dec("amount", 12345, 2);
+    logger.log_u64_as_fp("price", 3 << 15, 16);
+    logger.log_str("name", "say \"hi\"\n");
+    logger.log_loc("lib.rs", 3);
+    let _ = std::panic::catch_unwind(|| cvlr::asserts::cvlr_assert!(false, "x is eight"));
+
+    let trace = take_trace();
+    assert_eq!(trace.status(), "violated");
+    assert_eq!(
+        trace.to_json(),
+        compact_json(include_str!("fixtures/calltrace.json"))
+    );
+}
+
+#[test]
+fn test_trace_status() {
+    clear_trace();
+    cvlr::asserts::cvlr_assert!(true);
+    assert_eq!(take_trace().status(), "passed");
+
+    let _ = std::panic::catch_unwind(|| cvlr::asserts::cvlr_assume!(false));
+    let trace = take_trace();
+    assert_eq!(trace.status(), "discarded");
+    let mut out = Vec::new();
+    trace.write_json(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            r#"{"status":"SANITY_FAILED","jumpToDefinition":null,"assertMessage":[],"#,
+            r#""callTrace":{"message":"rule","type":"ScopeInstance","status":"SANITY_FAILED","#,
+            r#""jumpToDefinition":null,"childrenList":[{"message":"assume","type":"AssumeInstance","#,
+            r#""status":"VIOLATED","jumpToDefinition":null,"childrenList":[]}]}}"#,
+        )
+    );
+}
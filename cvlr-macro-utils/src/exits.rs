@@ -0,0 +1,137 @@
+//! Redirecting the early exits of a function body
+//!
+//! Macros that run code after a body (contracts, exit hooks) move the body
+//! into a labeled block and turn every `return` and `?` into a `break` to the
+//! label.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    parse_quote,
+    visit_mut::{self, VisitMut},
+    Expr, Item, Lifetime, Macro, ReturnType, Type,
+};
+
+/// Whether `tokens` contain a `?` or a `return`, at any depth
+///
+/// The tokens of a macro invocation are not parsed, so exits inside them
+/// cannot be rewritten and must be reported instead.
+pub fn macro_exits(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Punct(p) => p.as_char() == '?',
+        TokenTree::Ident(i) => i == "return",
+        TokenTree::Group(g) => macro_exits(g.stream()),
+        TokenTree::Literal(_) => false,
+    })
+}
+
+/// Redirects every exit of a function body to the end of a labeled block
+pub struct ExitRewriter {
+    pub label: Lifetime,
+    /// Whether the function returns an `Option`, which decides how `?` is desugared
+    returns_option: bool,
+    /// Whether an exit was redirected to the label
+    pub rewritten: bool,
+    /// The first `?` or `return` found inside a macro invocation
+    pub error: Option<syn::Error>,
+    /// Message of `error`
+    macro_error: &'static str,
+}
+
+impl ExitRewriter {
+    /// Rewriter for the body of a function returning `ret_type`, that breaks
+    /// to `label` and reports exits inside macros with `macro_error`
+    pub fn new(ret_type: &ReturnType, label: &str, macro_error: &'static str) -> Self {
+        let returns_option = match ret_type {
+            ReturnType::Type(_, ty) => match ty.as_ref() {
+                Type::Path(p) => p
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == "Option"),
+                _ => false,
+            },
+            ReturnType::Default => false,
+        };
+        Self {
+            label: Lifetime::new(label, Span::call_site()),
+            returns_option,
+            rewritten: false,
+            error: None,
+            macro_error,
+        }
+    }
+
+    /// `break 'label value`
+    ///
+    /// A value starting with `::` is parenthesized, since syn would read
+    /// `'label ::path` as a labeled block when the output is parsed again.
+    fn exit(&mut self, value: Option<Expr>) -> Expr {
+        self.rewritten = true;
+        let label = &self.label;
+        match value {
+            Some(v) if starts_with_path_sep(&v) => parse_quote!(break #label (#v)),
+            Some(v) => parse_quote!(break #label #v),
+            None => parse_quote!(break #label),
+        }
+    }
+}
+
+fn starts_with_path_sep(expr: &Expr) -> bool {
+    matches!(
+        expr.to_token_stream().into_iter().next(),
+        Some(TokenTree::Punct(p)) if p.as_char() == ':'
+    )
+}
+
+impl VisitMut for ExitRewriter {
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        // -- `return` and `?` inside these exit the closure or block, not the function
+        if matches!(node, Expr::Closure(_) | Expr::Async(_) | Expr::Const(_)) {
+            return;
+        }
+
+        // -- recurse first, since the operand of `return` or `?` may exit too
+        visit_mut::visit_expr_mut(self, node);
+
+        match node {
+            Expr::Return(ret) => {
+                let value = ret.expr.take().map(|v| *v);
+                *node = self.exit(value);
+            }
+            Expr::Try(expr) => {
+                let prefix = expr.expr.clone();
+                *node = if self.returns_option {
+                    let exit = self.exit(Some(parse_quote!(::core::option::Option::None)));
+                    parse_quote! {
+                        match #prefix {
+                            ::core::option::Option::Some(__cvlr_v) => __cvlr_v,
+                            ::core::option::Option::None => #exit,
+                        }
+                    }
+                } else {
+                    let exit = self.exit(Some(parse_quote! {
+                        ::core::result::Result::Err(::core::convert::From::from(__cvlr_e))
+                    }));
+                    parse_quote! {
+                        match #prefix {
+                            ::core::result::Result::Ok(__cvlr_v) => __cvlr_v,
+                            ::core::result::Result::Err(__cvlr_e) => #exit,
+                        }
+                    }
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Reports `?` and `return` inside macro invocations, which cannot be redirected
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        if self.error.is_none() && macro_exits(mac.tokens.clone()) {
+            self.error = Some(syn::Error::new_spanned(mac, self.macro_error));
+        }
+    }
+
+    // -- nested items have their own exits
+    fn visit_item_mut(&mut self, _node: &mut Item) {}
+}
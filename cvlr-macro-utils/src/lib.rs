@@ -0,0 +1,5 @@
+//! Internal helpers shared by the cvlr procedural macros
+//!
+//! Not a public API: items may change in any release.
+
+pub mod exits;
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Expr, Lit, Token};
+use syn::{parse::Parse, parse_macro_input, Expr, Ident, Lit, Token};
 
 // Custom parser for the assert_that DSL
 struct AssertThatInput {
@@ -28,6 +28,118 @@ pub fn assert_that_impl(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+// Bounded quantifier of the DSL: `forall!(i in range, cond)` or `exists!(i in range, cond)`
+struct Quantifier {
+    exists: bool,
+    var: Ident,
+    range: Expr,
+    body: Expr,
+}
+
+struct QuantifierArgs {
+    var: Ident,
+    range: Expr,
+    body: Expr,
+}
+
+impl Parse for QuantifierArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let var: Ident = input.parse()?;
+        let _: Token![in] = input.parse()?;
+        let range: Expr = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let body: Expr = input.parse()?;
+        // Allow a trailing comma
+        if input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+        }
+        Ok(QuantifierArgs { var, range, body })
+    }
+}
+
+/// Returns true if `expr` is a `forall!` or `exists!` invocation
+fn is_quantifier(expr: &Expr) -> bool {
+    matches!(expr, Expr::Macro(mac)
+        if mac.mac.path.is_ident("forall") || mac.mac.path.is_ident("exists"))
+}
+
+/// Returns true if `expr` is a quantifier, or contains one as an operand of
+/// `&&`, `||` or `!`
+pub fn contains_quantifier(expr: &Expr) -> bool {
+    match unwrap_groups(expr) {
+        Expr::Binary(bin) if is_logical(&bin.op) => {
+            contains_quantifier(&bin.left) || contains_quantifier(&bin.right)
+        }
+        Expr::Unary(un) if matches!(un.op, syn::UnOp::Not(_)) => contains_quantifier(&un.expr),
+        expr => is_quantifier(expr),
+    }
+}
+
+fn is_logical(op: &syn::BinOp) -> bool {
+    matches!(op, syn::BinOp::And(_) | syn::BinOp::Or(_))
+}
+
+// Returns the quantifier if `expr` is a `forall!` or `exists!` invocation
+fn as_quantifier(expr: &Expr) -> syn::Result<Option<Quantifier>> {
+    let Expr::Macro(mac) = expr else {
+        return Ok(None);
+    };
+    if !is_quantifier(expr) {
+        return Ok(None);
+    }
+    let exists = mac.mac.path.is_ident("exists");
+    let args: QuantifierArgs = mac.mac.parse_body()?;
+    Ok(Some(Quantifier {
+        exists,
+        var: args.var,
+        range: args.range,
+        body: args.body,
+    }))
+}
+
+// Boolean expression of a quantifier, evaluated over every index in the range
+fn quantifier_eval(q: &Quantifier) -> syn::Result<TokenStream2> {
+    let Quantifier {
+        exists,
+        var,
+        range,
+        body,
+    } = q;
+    let body = eval_expr(body)?;
+    let method = if *exists { quote!(any) } else { quote!(all) };
+    Ok(quote! {
+        ::core::iter::IntoIterator::into_iter(#range).#method(|#var| #body)
+    })
+}
+
+// Asserts (or assumes) a quantifier.
+//
+// `forall` is unrolled into a loop that checks the body for each index, so
+// that comparisons in the body are logged. `exists` checks the evaluated
+// disjunction.
+fn quantifier_check(q: &Quantifier, macro_name: &str) -> syn::Result<TokenStream2> {
+    if q.exists {
+        let cond = quantifier_eval(q)?;
+        let macro_ident = syn::Ident::new(macro_name, Span::call_site());
+        return Ok(quote! {
+            ::cvlr::asserts::#macro_ident!(#cond);
+        });
+    }
+    let Quantifier {
+        var, range, body, ..
+    } = q;
+    let body = if macro_name == "cvlr_assume" {
+        analyze_assume_condition(body)?
+    } else {
+        analyze_condition(body)?
+    };
+    Ok(quote! {
+        for #var in #range {
+            #body
+        }
+    })
+}
+
 // Helper function to unwrap Expr::Group and Expr::Paren expressions
 fn unwrap_groups(expr: &Expr) -> &Expr {
     match expr {
@@ -79,6 +191,11 @@ fn handle_boolean_condition_with_macro(
     condition: &Expr,
     macro_name: &str,
 ) -> syn::Result<TokenStream2> {
+    // Check if condition is a bounded quantifier
+    if let Some(q) = as_quantifier(condition)? {
+        return quantifier_check(&q, macro_name);
+    }
+
     // Check if condition is literal `true`
     if let Expr::Lit(lit) = condition {
         if let Lit::Bool(lit_bool) = &lit.lit {
@@ -166,7 +283,9 @@ fn handle_boolean_condition_with_macro(
             })
         }
     } else {
-        // Regular condition - generate macro call
+        // Regular condition - generate macro call, with any nested quantifiers
+        // expanded to their boolean expression
+        let condition = eval_expr(condition)?;
         let macro_ident = syn::Ident::new(macro_name, Span::call_site());
         Ok(quote! {
             ::cvlr::asserts::#macro_ident!(#condition);
@@ -298,7 +417,34 @@ pub fn assume_all_impl(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Boolean expression of `condition`, with bounded quantifiers expanded
+///
+/// Quantifiers are expanded at the top level and in the operands of `&&`,
+/// `||` and `!`; anywhere else they are left as macro invocations.
+pub fn eval_expr(condition: &Expr) -> syn::Result<TokenStream2> {
+    if !contains_quantifier(condition) {
+        return Ok(quote! { #condition });
+    }
+    match unwrap_groups(condition) {
+        Expr::Binary(bin) => {
+            let left = eval_expr(&bin.left)?;
+            let op = &bin.op;
+            let right = eval_expr(&bin.right)?;
+            Ok(quote! { (#left) #op (#right) })
+        }
+        Expr::Unary(un) => {
+            let inner = eval_expr(&un.expr)?;
+            Ok(quote! { !(#inner) })
+        }
+        expr => match as_quantifier(expr)? {
+            Some(q) => quantifier_eval(&q),
+            None => Ok(quote! { #condition }),
+        },
+    }
+}
+
 pub fn analyze_eval_condition(condition: &Expr) -> syn::Result<TokenStream2> {
+    let condition = eval_expr(condition)?;
     // Expression: { condition }
     Ok(quote! {
         {
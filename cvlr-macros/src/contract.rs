@@ -0,0 +1,232 @@
+use cvlr_macro_utils::exits::ExitRewriter;
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse::Parser,
+    parse_quote,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    Attribute, Expr, ItemFn, Token,
+};
+
+use crate::{
+    assert_that::{analyze_assume_condition, analyze_condition},
+    when::When,
+};
+
+/// Kind of a contract clause
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClauseKind {
+    Requires,
+    Ensures,
+}
+
+impl ClauseKind {
+    /// The kind of a contract attribute: `requires` or `ensures`, bare or
+    /// under `cvlr::` or `cvlr_macros::`
+    fn of(attr: &Attribute) -> Option<Self> {
+        let path = attr.path();
+        let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+        let name = match segments.as_slice() {
+            [name] if path.leading_colon.is_none() => name,
+            [krate, name] if krate == "cvlr" || krate == "cvlr_macros" => name,
+            _ => return None,
+        };
+        match name.as_str() {
+            "requires" => Some(Self::Requires),
+            "ensures" => Some(Self::Ensures),
+            _ => None,
+        }
+    }
+}
+
+/// A `requires` or `ensures` clause: a condition, optionally followed by
+/// `when = ..`, which is kept with its argument for error reporting
+struct Clause {
+    kind: ClauseKind,
+    cond: Expr,
+    when: Option<(When, Expr)>,
+}
+
+impl Clause {
+    fn parse(kind: ClauseKind, tokens: TokenStream2) -> syn::Result<Self> {
+        let args = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(tokens)?;
+        let mut args = args.into_iter();
+        let cond = args.next().ok_or_else(|| {
+            syn::Error::new(proc_macro2::Span::call_site(), "expected a condition")
+        })?;
+        let mut when = None;
+        for arg in args {
+            match &arg {
+                Expr::Assign(assign) if is_when(&assign.left) && when.is_none() => {
+                    when = Some((When::parse(&assign.right)?, arg.clone()));
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "expected a single condition, optionally followed by `when = \"...\"`",
+                    ))
+                }
+            }
+        }
+        Ok(Self { kind, cond, when })
+    }
+}
+
+fn is_when(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(p) if p.path.is_ident("when"))
+}
+
+/// Replaces `old(expr)` in an `ensures` clause by a variable that holds the
+/// value of `expr` on entry
+struct OldRewriter {
+    olds: Vec<(syn::Ident, Expr)>,
+}
+
+impl VisitMut for OldRewriter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Call(call) = expr {
+            if matches!(call.func.as_ref(), Expr::Path(p) if p.path.is_ident("old"))
+                && call.args.len() == 1
+            {
+                let ident = format_ident!("__cvlr_old_{}", self.olds.len());
+                self.olds.push((ident.clone(), call.args[0].clone()));
+                *expr = parse_quote!(#ident);
+                return;
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Whether `expr` contains a call to `old`
+fn mentions_old(expr: &Expr) -> bool {
+    let mut rewriter = OldRewriter { olds: Vec::new() };
+    rewriter.visit_expr_mut(&mut expr.clone());
+    !rewriter.olds.is_empty()
+}
+
+fn contract_impl(kind: ClauseKind, attr: TokenStream, item: TokenStream) -> TokenStream {
+    match contract(kind, attr.into(), item.into()) {
+        Ok(tks) => tks.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+pub fn requires_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract_impl(ClauseKind::Requires, attr, item)
+}
+
+pub fn ensures_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract_impl(ClauseKind::Ensures, attr, item)
+}
+
+fn contract(kind: ClauseKind, attr: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut fn_ast: ItemFn = syn::parse2(item)?;
+
+    // -- the first contract attribute collects the clauses of all the others,
+    // -- so that a single wrapper checks them all
+    let mut clauses = vec![Clause::parse(kind, attr)?];
+    let mut attrs = Vec::new();
+    for attr in fn_ast.attrs.drain(..) {
+        match ClauseKind::of(&attr) {
+            Some(kind) => {
+                let tokens = attr.meta.require_list()?.tokens.clone();
+                clauses.push(Clause::parse(kind, tokens)?);
+            }
+            None => attrs.push(attr),
+        }
+    }
+    fn_ast.attrs = attrs;
+
+    if let Some(asyncness) = &fn_ast.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "contracts are not supported on async functions",
+        ));
+    }
+
+    let mut when: Option<When> = None;
+    for clause in &mut clauses {
+        if let Some((new, arg)) = clause.when.take() {
+            match &when {
+                Some(prev) if !prev.same_as(&new) => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "all clauses of a contract must use the same `when`",
+                    ))
+                }
+                _ => when = Some(new),
+            }
+        }
+    }
+    let when = when.unwrap_or_default();
+    let cfg = when.cfg();
+    let cfg_not = when.cfg_not();
+
+    let mut requires = Vec::new();
+    let mut ensures = Vec::new();
+    let mut rewriter = OldRewriter { olds: Vec::new() };
+    for clause in clauses {
+        let mut cond = clause.cond;
+        match clause.kind {
+            ClauseKind::Requires => {
+                if mentions_old(&cond) {
+                    return Err(syn::Error::new_spanned(
+                        &cond,
+                        "`old` can only be used in `ensures`",
+                    ));
+                }
+                requires.push(analyze_assume_condition(&cond)?);
+            }
+            ClauseKind::Ensures => {
+                rewriter.visit_expr_mut(&mut cond);
+                ensures.push(analyze_condition(&cond)?);
+            }
+        }
+    }
+    let olds = rewriter.olds.iter().map(|(ident, expr)| {
+        quote! { let #ident = ::core::clone::Clone::clone(&(#expr)); }
+    });
+
+    // -- the body runs in a labeled block, so that `return` and `?` leave the
+    // -- body and not the wrapper
+    let mut rewriter = ExitRewriter::new(
+        &fn_ast.sig.output,
+        "'__cvlr_body",
+        "contracts cannot rewrite `?` or `return` inside a macro invocation",
+    );
+    let mut stmts = fn_ast.block.stmts.clone();
+    for stmt in &mut stmts {
+        rewriter.visit_stmt_mut(stmt);
+    }
+    if let Some(e) = rewriter.error {
+        return Err(e);
+    }
+    // -- the label is only needed if some exit breaks to it
+    let body = if rewriter.rewritten {
+        let label = &rewriter.label;
+        quote! { #label: { #(#stmts)* } }
+    } else {
+        quote! { { #(#stmts)* } }
+    };
+    let attrs = &fn_ast.attrs;
+    let vis = &fn_ast.vis;
+    let sig = &fn_ast.sig;
+
+    Ok(quote! {
+        #cfg_not
+        #fn_ast
+
+        #cfg
+        #(#attrs)*
+        #vis #sig {
+            #(#requires)*
+            #(#olds)*
+            let result = #body;
+            #(#ensures)*
+            result
+        }
+    })
+}
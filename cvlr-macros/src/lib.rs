@@ -1,13 +1,26 @@
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{parse_macro_input, parse_quote, Ident, ItemFn};
+use quote::quote;
+use syn::{parse_macro_input, Ident};
 
 mod assert_that;
+mod contract;
 mod mock;
 mod predicate;
+mod rule;
 mod rule_for_spec;
+mod summary;
+mod when;
 /// Mark a method as a CVT rule
 ///
+/// A rule may take parameters, like a CVL rule. Each parameter is created with
+/// `cvlr::nondet::nondet` and logged under its name, so the rule
+/// itself is a function without arguments. A parameter of type `&T` or
+/// `&mut T` refers to a nondet `T`, so `T` cannot be unsized, such as `[u8]`
+/// or `str`; such values are created in the rule instead.
+///
+/// The rule is registered in the rule manifest (see `cvlr::manifest`), with
+/// the tags given by `#[rule(tags = ["...", ...])]`.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -16,30 +29,160 @@ mod rule_for_spec;
 /// fn foo()  {
 ///    cvlr_assert!(false);
 /// }
+///
+/// #[rule]
+/// fn add_is_monotone(x: u64, y: u64) {
+///     cvlr_assume!(x.checked_add(y).is_some());
+///     cvlr_assert!(x + y >= x);
+/// }
+///
+/// #[rule(tags = ["arith"])]
+/// fn mul_by_one(x: u64) {
+///     cvlr_assert!(x * 1 == x);
+/// }
 /// ```
 #[proc_macro_attribute]
-pub fn rule(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let mut fn_ast = parse_macro_input!(item as ItemFn);
-    // add #[no_mangle] attribute
-    fn_ast.attrs.push(parse_quote! { #[no_mangle] });
-    // The first statement in rules is a call to the macro `cvlr_rule_location!`
-    // to automatically insert the location of the rule.
-    fn_ast
-        .block
-        .stmts
-        .insert(0, parse_quote! { cvlr::log::cvlr_rule_location!(); });
-    fn_ast
-        .block
-        .stmts
-        .push(parse_quote! { cvlr::cvlr_vacuity_check!(); });
-    fn_ast.into_token_stream().into()
+pub fn rule(attr: TokenStream, item: TokenStream) -> TokenStream {
+    rule::rule_impl(attr, item)
 }
 
+/// Replace a function by a mock in verification builds
+///
+/// When `when` holds, the body of the function forwards its arguments, and its
+/// receiver if any, to the function given by `with`; otherwise, the function
+/// is left as is. This works for free functions, associated functions and
+/// methods, including trait impls, and generic functions, whose mock is called
+/// without explicit generic arguments.
+///
+/// `when` is either the name of a feature, as in `when = "certora"`, which is
+/// the default, or a cfg predicate, as in `when = not(feature = "std")` or
+/// `when = any(test, feature = "certora")`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cvlr::prelude::*;
+///
+/// struct Oracle;
+///
+/// fn mock_price(_: &Oracle, _: u32) -> u64 {
+///     nondet()
+/// }
+///
+/// impl Oracle {
+///     #[mock_fn(with = mock_price)]
+///     fn price(&self, asset: u32) -> u64 {
+///         unimplemented!("off-chain lookup of {}", asset)
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn mock_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
     mock::mock_fn_impl(attr, item)
 }
 
+/// Attach a precondition to a function
+///
+/// In verification builds, that is, when `when` holds (by default, when the
+/// `certora` feature is enabled; see [`mock_fn`](macro@mock_fn) for the
+/// syntax), the function is replaced by a wrapper that
+/// assumes the precondition on entry and checks the postconditions given by
+/// [`ensures`](macro@ensures) on exit. Otherwise, the function is left as is.
+///
+/// The condition uses the same syntax as [`cvlr_assume_that!`] and refers to
+/// the parameters of the function by name. A function may have any number of
+/// `requires` and `ensures` clauses; if some give `when`, they must agree.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// #[cvlr::requires(amount <= balance)]
+/// #[cvlr::ensures(result == old(balance) - amount)]
+/// fn withdraw(balance: u64, amount: u64) -> u64 {
+///     balance - amount
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract::requires_impl(attr, item)
+}
+
+/// Attach a postcondition to a function
+///
+/// The condition uses the same syntax as [`cvlr_assert_that!`] and is asserted
+/// when the function returns. It refers to the return value as `result`, and to
+/// the value of an expression on entry as `old(expr)`; `old` values are
+/// cloned before the body runs. See [`requires`](macro@requires) for when the
+/// check is generated.
+///
+/// Every `return` and `?` of the body is redirected to the check. They cannot
+/// be redirected inside macro invocations, where they are a compile error.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// struct Vault {
+///     shares: u64,
+/// }
+///
+/// impl Vault {
+///     #[cvlr::ensures(self.shares == old(self.shares) + amount)]
+///     #[cvlr::ensures(result.is_ok(), when = "certora")]
+///     fn deposit(&mut self, amount: u64) -> Result<(), ()> {
+///         self.shares = self.shares.checked_add(amount).ok_or(())?;
+///         Ok(())
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
+    contract::ensures_impl(attr, item)
+}
+
+/// Replace a function by its specification
+///
+/// In verification builds, that is, when `when` holds (by default, when the
+/// `certora` feature is enabled; see [`mock_fn`](macro@mock_fn) for the
+/// syntax), the body of the function is replaced by a
+/// summary: the requires of `spec` are asserted, the `&mut` parameters and the
+/// return value are havoced with `cvlr::nondet::nondet`, and the
+/// ensures of `spec` are assumed over the new and the old context. Otherwise,
+/// the function is left as is.
+///
+/// `spec` is any expression implementing `cvlr::spec::CvlrSpec`.
+/// Its context is the parameter named by `ctx`, which must be a reference; it
+/// defaults to the only `&mut` parameter. The context must implement `Clone`,
+/// and every `&mut` parameter and the return value must implement `Nondet`.
+///
+/// The ensures of `spec` only see the context, so the havoced return value is
+/// unconstrained. A function whose result matters should return it through the
+/// context instead, or keep its body.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use cvlr::prelude::*;
+///
+/// #[derive(Clone, Nondet, CvlrLog)]
+/// struct Pool {
+///     reserve: u64,
+/// }
+///
+/// #[cvlr::summary(spec = cvlr_spec(
+///     cvlr_fn(|c: &Pool| c.reserve >= 10),
+///     cvlr_fn2(|c: &Pool, old: &Pool| c.reserve <= old.reserve),
+/// ))]
+/// fn drain(pool: &mut Pool) -> u64 {
+///     let out = pool.reserve / 2;
+///     pool.reserve -= out;
+///     out
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn summary(attr: TokenStream, item: TokenStream) -> TokenStream {
+    summary::summary_impl(attr, item)
+}
+
 /// Converts a function into a CVLR predicate.
 ///
 /// This attribute macro transforms a function into a struct that implements
@@ -74,6 +217,9 @@ pub fn mock_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * The first parameter represents the post-state, the second represents the pre-state
 /// * The function body can contain one or more expressions (statements ending with `;`)
 /// * The function name will be converted from snake_case to PascalCase for the struct name
+/// * An expression may be a bounded quantifier, `forall!(i in range, cond)` or
+///   `exists!(i in range, cond)`, possibly nested in `&&`, `||` or `!`, with the
+///   same meaning as in [`cvlr_assert_that!`]
 ///
 /// # Examples
 ///
@@ -215,6 +361,20 @@ pub fn cvlr_predicate(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// cvlr_assert_that!(x + 1 > 0 && y * 2 < z); // expands to cvlr_assert!(x + 1 > 0 && y * 2 < z)
 /// ```
 ///
+/// ## Bounded quantifiers
+///
+/// ```rust,no_run
+/// use cvlr_macros::cvlr_assert_that;
+///
+/// let balances = [1u64, 2, 3];
+/// let cap = 3u64;
+///
+/// // expands to a loop asserting cvlr_assert_le!(balances[i], cap) for each i
+/// cvlr_assert_that!(forall!(i in 0..balances.len(), balances[i] <= cap));
+/// // expands to cvlr_assert!((0..3).any(|i| balances[i] == 1))
+/// cvlr_assert_that!(exists!(i in 0..3, balances[i] == 1));
+/// ```
+///
 /// # Expansion
 ///
 /// The macro automatically detects comparison operators and expands to the
@@ -222,6 +382,14 @@ pub fn cvlr_predicate(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// - Comparisons (`<`, `<=`, `>`, `>=`, `==`, `!=`) expand to `cvlr_assert_<op>!`
 /// - Boolean expressions expand to `cvlr_assert!`
+/// - `forall!(i in range, cond)` expands to a loop over `range` that asserts `cond`
+/// - `exists!(i in range, cond)` expands to `cvlr_assert!` of `range.any(|i| cond)`
+///
+/// A quantifier nested in `&&`, `||` or `!` is expanded to its boolean
+/// expression inside a single `cvlr_assert!`; quantifiers in any other position
+/// (e.g. a function argument) are not recognized. The quantifiers over a
+/// nondeterministic index, `cvlr_forall_nondet` and `cvlr_exists_nondet` of
+/// `cvlr-spec`, have no form in this DSL.
 #[proc_macro]
 pub fn cvlr_assert_that(input: TokenStream) -> TokenStream {
     assert_that::assert_that_impl(input)
@@ -1,16 +1,14 @@
 use darling::{ast::NestedMeta, FromMeta};
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, ItemFn, Path};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, FnArg, ItemFn, Pat, PatIdent, Path};
 
-fn default_when_feature() -> String {
-    "certora".into()
-}
+use crate::when::When;
 
-#[derive(Debug, FromMeta)]
+#[derive(FromMeta)]
 struct MockFnArgs {
-    #[darling(default = "default_when_feature")]
-    when: String,
+    #[darling(default)]
+    when: When,
     with: Path,
 }
 
@@ -27,21 +25,62 @@ pub fn mock_fn_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(e) => return e.write_errors().into(),
     };
 
-    let when = args.when;
+    let cfg = args.when.cfg();
+    let cfg_not = args.when.cfg_not();
     let mock_fn = args.with;
 
     let fn_ast = parse_macro_input!(item as ItemFn);
 
-    let vis = fn_ast.vis.clone();
-    let ident = fn_ast.sig.ident.clone();
+    // -- the mock has the same signature, and a body that forwards the
+    // -- receiver and the arguments to `with`, so that it also works for
+    // -- methods, trait impls, and generic functions
+    let mut mock_ast = fn_ast.clone();
+    let mut call_args = Vec::new();
+    for (i, input) in mock_ast.sig.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(recv) => {
+                let self_token = recv.self_token;
+                call_args.push(quote! { #self_token });
+            }
+            FnArg::Typed(pat_type) => {
+                let ident = match pat_type.pat.as_ref() {
+                    Pat::Ident(PatIdent {
+                        ident,
+                        subpat: None,
+                        ..
+                    }) => ident.clone(),
+                    _ => format_ident!("__cvlr_arg_{}", i),
+                };
+                *pat_type.pat = Pat::Ident(PatIdent {
+                    attrs: Vec::new(),
+                    by_ref: None,
+                    mutability: None,
+                    ident: ident.clone(),
+                    subpat: None,
+                });
+                call_args.push(quote! { #ident });
+            }
+        }
+    }
+    let call = if mock_ast.sig.asyncness.is_some() {
+        quote! { #mock_fn(#(#call_args),*).await }
+    } else {
+        quote! { #mock_fn(#(#call_args),*) }
+    };
+    let attrs = &mock_ast.attrs;
+    let vis = &mock_ast.vis;
+    let sig = &mock_ast.sig;
 
     let tks = quote! {
 
-        #[cfg(not(feature = #when))]
+        #cfg_not
         #fn_ast
 
-        #[cfg(feature = #when)]
-        #vis use #mock_fn as #ident;
+        #cfg
+        #(#attrs)*
+        #vis #sig {
+            #call
+        }
     };
     tks.into()
 }
@@ -3,7 +3,9 @@ use proc_macro2::Span;
 use quote::quote;
 use syn::{parse_macro_input, Expr, FnArg, ItemFn, Pat, PatType, Stmt, Type, TypeReference};
 
-use crate::assert_that::{analyze_assume_condition, analyze_condition};
+use crate::assert_that::{
+    analyze_assume_condition, analyze_condition, contains_quantifier, eval_expr,
+};
 
 /// Converts a snake_case identifier to PascalCase
 pub fn to_pascal_case(s: &str) -> String {
@@ -78,6 +80,11 @@ fn separate_statements(stmts: &[Stmt]) -> syn::Result<(Vec<&Stmt>, Vec<Expr>)> {
                     expressions.push(expr.clone());
                 }
             }
+            // A macro in statement position, e.g. a `forall!` quantifier
+            Stmt::Macro(mac) => expressions.push(Expr::Macro(syn::ExprMacro {
+                attrs: mac.attrs.clone(),
+                mac: mac.mac.clone(),
+            })),
             _ => {
                 return Err(syn::Error::new(
                     Span::call_site(),
@@ -90,6 +97,26 @@ fn separate_statements(stmts: &[Stmt]) -> syn::Result<(Vec<&Stmt>, Vec<Expr>)> {
     Ok((let_statements, expressions))
 }
 
+/// Replaces the bounded quantifiers in the body of the kept function by their
+/// boolean expression, since `forall!` and `exists!` are not macros on their own
+fn expand_quantifiers(fn_item: &mut ItemFn) -> syn::Result<()> {
+    for stmt in &mut fn_item.block.stmts {
+        let expr = match stmt {
+            Stmt::Macro(mac) => Expr::Macro(syn::ExprMacro {
+                attrs: mac.attrs.clone(),
+                mac: mac.mac.clone(),
+            }),
+            Stmt::Expr(expr, _) => expr.clone(),
+            _ => continue,
+        };
+        if contains_quantifier(&expr) {
+            let expanded: Expr = syn::parse2(eval_expr(&expr)?)?;
+            *stmt = Stmt::Expr(expanded, Some(Default::default()));
+        }
+    }
+    Ok(())
+}
+
 pub fn cvlr_predicate_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let fn_item = parse_macro_input!(item as ItemFn);
 
@@ -146,6 +173,11 @@ pub fn cvlr_predicate_impl(_attr: TokenStream, item: TokenStream) -> TokenStream
         Err(e) => return e.to_compile_error().into(),
     };
 
+    let mut kept_fn = fn_item.clone();
+    if let Err(e) = expand_quantifiers(&mut kept_fn) {
+        return e.to_compile_error().into();
+    }
+
     // Generate assert statements using analyze_condition
     let mut assert_statements = Vec::new();
     for expr in &expressions {
@@ -167,6 +199,10 @@ pub fn cvlr_predicate_impl(_attr: TokenStream, item: TokenStream) -> TokenStream
     // Build the eval block with lazy evaluation using an accumulator variable
     let mut eval_statements = Vec::new();
     for expr in &expressions {
+        let expr = match eval_expr(expr) {
+            Ok(expr) => expr,
+            Err(e) => return e.to_compile_error().into(),
+        };
         eval_statements.push(quote! {
             __cvlr_eval_res = __cvlr_eval_res && { #expr };
         });
@@ -183,8 +219,8 @@ pub fn cvlr_predicate_impl(_attr: TokenStream, item: TokenStream) -> TokenStream
         quote! {
             // Keep the original function so IDEs can report errors
             // But mark it dead code and unused must use to avoid warnings
-            #[allow(unused_must_use, dead_code)]
-            #fn_item
+            #[allow(unused_must_use, dead_code, clippy::no_effect)]
+            #kept_fn
 
             #vis struct #struct_name;
 
@@ -239,8 +275,8 @@ pub fn cvlr_predicate_impl(_attr: TokenStream, item: TokenStream) -> TokenStream
         quote! {
             // Keep the original function so IDEs can report errors
             // But mark it dead code and unused must use to avoid warnings
-            #[allow(unused_must_use, dead_code)]
-            #fn_item
+            #[allow(unused_must_use, dead_code, clippy::no_effect)]
+            #kept_fn
 
             #vis struct #struct_name;
 
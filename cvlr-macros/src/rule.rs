@@ -0,0 +1,133 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::Parser, parse_macro_input, parse_quote, punctuated::Punctuated, Expr, ExprLit, FnArg,
+    ItemFn, Lit, LitStr, MetaNameValue, Pat, PatIdent, Stmt, Token, Type,
+};
+
+/// Tags given by `#[rule(tags = ["...", ...])]`
+fn parse_tags(attr: TokenStream2) -> syn::Result<Vec<LitStr>> {
+    let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(attr)?;
+    let mut tags = Vec::new();
+    for arg in args {
+        let elems = match &arg.value {
+            Expr::Array(array) if arg.path.is_ident("tags") => &array.elems,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `tags = [\"...\", ...]`",
+                ))
+            }
+        };
+        for elem in elems {
+            match elem {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => tags.push(lit.clone()),
+                elem => return Err(syn::Error::new_spanned(elem, "expected a string")),
+            }
+        }
+    }
+    Ok(tags)
+}
+
+/// Whether `ty` is a slice, `str`, or trait object, which `nondet` cannot create
+fn is_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Path(p) => p.qself.is_none() && p.path.is_ident("str"),
+        Type::Paren(p) => is_unsized(&p.elem),
+        _ => false,
+    }
+}
+
+/// Statements that create a rule parameter with `nondet` and log it
+fn nondet_param(i: usize, arg: &FnArg) -> syn::Result<TokenStream2> {
+    let pat_type = match arg {
+        FnArg::Receiver(recv) => {
+            return Err(syn::Error::new_spanned(recv, "rules cannot take `self`"))
+        }
+        FnArg::Typed(pat_type) => pat_type,
+    };
+    let pat = &pat_type.pat;
+    let name = match pat.as_ref() {
+        Pat::Ident(PatIdent { ident, .. }) => ident.to_string(),
+        pat => pat.to_token_stream().to_string(),
+    };
+    let value = format_ident!("__cvlr_arg_{}", i);
+
+    // -- a reference parameter refers to a nondet value owned by the rule
+    let (ty, arg) = match pat_type.ty.as_ref() {
+        Type::Reference(r) if is_unsized(&r.elem) => {
+            return Err(syn::Error::new_spanned(
+                &r.elem,
+                "rule parameters cannot refer to unsized types; create the value in the rule instead, e.g. with `nondet_vec` or `nondet_string`",
+            ))
+        }
+        Type::Reference(r) => {
+            let mutability = &r.mutability;
+            (r.elem.as_ref(), quote! { &#mutability #value })
+        }
+        ty => (ty, quote! { #value }),
+    };
+    let binding = match pat_type.ty.as_ref() {
+        Type::Reference(r) if r.mutability.is_some() => quote! { mut },
+        _ => quote! {},
+    };
+    Ok(quote! {
+        let #binding #value: #ty = ::cvlr::nondet::nondet();
+        ::cvlr::log::cvlr_log!(#value => #name);
+        let #pat = #arg;
+    })
+}
+
+pub fn rule_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let tags = match parse_tags(attr.into()) {
+        Ok(tags) => tags,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut fn_ast = parse_macro_input!(item as ItemFn);
+
+    if !fn_ast.sig.generics.params.is_empty() {
+        return syn::Error::new_spanned(&fn_ast.sig.generics, "rules cannot be generic")
+            .to_compile_error()
+            .into();
+    }
+
+    // -- parameters become nondet values created at the start of the rule,
+    // -- so that the entry point takes no arguments
+    let params = match fn_ast
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| nondet_param(i, arg))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(params) => params,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    fn_ast.sig.inputs.clear();
+    let params: Vec<Stmt> = parse_quote! { #(#params)* };
+
+    // add #[no_mangle] attribute
+    fn_ast.attrs.push(parse_quote! { #[no_mangle] });
+    // The first statement in rules is a call to the macro `cvlr_rule_location!`
+    // to automatically insert the location of the rule.
+    fn_ast.block.stmts.splice(0..0, params);
+    fn_ast
+        .block
+        .stmts
+        .insert(0, parse_quote! { cvlr::log::cvlr_rule_location!(); });
+    fn_ast
+        .block
+        .stmts
+        .push(parse_quote! { cvlr::cvlr_vacuity_check!(); });
+    let ident = &fn_ast.sig.ident;
+    quote! {
+        #fn_ast
+        ::cvlr::cvlr_register_rule!{#ident, tags: [#(#tags),*]}
+    }
+    .into()
+}
@@ -93,7 +93,9 @@ pub fn cvlr_rule_for_spec_impl(input: TokenStream) -> TokenStream {
     let base = &parsed.base;
 
     // Generate the macro call
+    let name = &parsed.name;
     let expanded = quote! {
+        ::cvlr::cvlr_register_rule!{#rule_name, tags: [#name]}
         cvlr_impl_rule!{#rule_name, #spec, #base}
     };
 
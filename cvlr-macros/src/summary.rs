@@ -0,0 +1,156 @@
+use darling::{ast::NestedMeta, FromMeta};
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, Expr, FnArg, Ident, ItemFn, Pat, ReturnType, Type};
+
+use crate::when::When;
+
+#[derive(FromMeta)]
+struct SummaryArgs {
+    #[darling(default)]
+    when: When,
+    spec: Expr,
+    ctx: Option<Ident>,
+}
+
+/// A reference parameter of the summarized function
+struct RefParam {
+    ident: Ident,
+    mutable: bool,
+}
+
+/// Reference parameters, including a `&self` or `&mut self` receiver
+fn ref_params(fn_ast: &ItemFn) -> syn::Result<Vec<RefParam>> {
+    let mut res = Vec::new();
+    for arg in &fn_ast.sig.inputs {
+        match arg {
+            FnArg::Receiver(recv) => {
+                if recv.reference.is_some() {
+                    res.push(RefParam {
+                        ident: Ident::new("self", recv.self_token.span),
+                        mutable: recv.mutability.is_some(),
+                    });
+                }
+            }
+            FnArg::Typed(pat_type) => {
+                if let Type::Reference(r) = pat_type.ty.as_ref() {
+                    match pat_type.pat.as_ref() {
+                        Pat::Ident(pat) => res.push(RefParam {
+                            ident: pat.ident.clone(),
+                            mutable: r.mutability.is_some(),
+                        }),
+                        pat if r.mutability.is_some() => {
+                            return Err(syn::Error::new_spanned(
+                                pat,
+                                "summary requires `&mut` parameters to be identifiers",
+                            ))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    Ok(res)
+}
+
+fn summary(args: SummaryArgs, fn_ast: ItemFn) -> syn::Result<TokenStream2> {
+    if let Some(asyncness) = &fn_ast.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "summaries are not supported on async functions",
+        ));
+    }
+
+    let params = ref_params(&fn_ast)?;
+    let ctx = match &args.ctx {
+        Some(ctx) => match params.iter().find(|p| p.ident == *ctx) {
+            Some(p) => p.ident.clone(),
+            None => {
+                return Err(syn::Error::new_spanned(
+                    ctx,
+                    "`ctx` must name a reference parameter",
+                ))
+            }
+        },
+        None => {
+            let mut muts = params.iter().filter(|p| p.mutable);
+            match (muts.next(), muts.next()) {
+                (Some(p), None) => p.ident.clone(),
+                _ => {
+                    return Err(syn::Error::new(
+                        Span::call_site(),
+                        "expected `ctx = <parameter>`, unless the function has exactly one `&mut` parameter",
+                    ))
+                }
+            }
+        }
+    };
+
+    let cfg = args.when.cfg();
+    let cfg_not = args.when.cfg_not();
+    let spec = &args.spec;
+    let havocs = params.iter().filter(|p| p.mutable).map(|p| {
+        let ident = &p.ident;
+        quote! { *#ident = ::cvlr::nondet::nondet(); }
+    });
+    let ret = match &fn_ast.sig.output {
+        ReturnType::Default => quote! {},
+        ReturnType::Type(_, ty) => quote! {
+            let __cvlr_ret: #ty = ::cvlr::nondet::nondet();
+        },
+    };
+    let ret_value = match &fn_ast.sig.output {
+        ReturnType::Default => quote! {},
+        ReturnType::Type(..) => quote! { __cvlr_ret },
+    };
+
+    // -- spanned on the context, so that a missing `Clone` is reported there
+    let old = quote_spanned! {ctx.span()=>
+        let __cvlr_old = ::core::clone::Clone::clone(&*#ctx);
+    };
+
+    let attrs = &fn_ast.attrs;
+    let vis = &fn_ast.vis;
+    let sig = &fn_ast.sig;
+
+    Ok(quote! {
+        #cfg_not
+        #fn_ast
+
+        #cfg
+        #(#attrs)*
+        #[allow(unused_variables, unused_mut)]
+        #vis #sig {
+            let __cvlr_spec = #spec;
+            ::cvlr::spec::CvlrSpec::assert_requires(&__cvlr_spec, &*#ctx);
+            #old
+            #(#havocs)*
+            #ret
+            ::cvlr::spec::CvlrSpec::assume_ensures(&__cvlr_spec, &*#ctx, &__cvlr_old);
+            #ret_value
+        }
+    })
+}
+
+pub fn summary_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match NestedMeta::parse_meta_list(attr.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return darling::Error::from(e).write_errors().into();
+        }
+    };
+
+    let args = match SummaryArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let fn_ast = parse_macro_input!(item as ItemFn);
+
+    match summary(args, fn_ast) {
+        Ok(tks) => tks.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
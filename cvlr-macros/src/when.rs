@@ -0,0 +1,83 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Expr, ExprLit, Lit};
+
+/// The `when` argument of attributes that only apply to verification builds
+///
+/// A string names a feature, as in `when = "certora"`. Anything else is a cfg
+/// predicate, as in `when = not(feature = "std")` or `when = any(test, kani)`.
+pub struct When(TokenStream2);
+
+impl Default for When {
+    fn default() -> Self {
+        Self(quote! { feature = "certora" })
+    }
+}
+
+impl When {
+    pub fn parse(expr: &Expr) -> syn::Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Ok(Self(quote! { feature = #lit })),
+            Expr::Group(group) => Self::parse(&group.expr),
+            expr => {
+                check_predicate(expr)?;
+                Ok(Self(quote! { #expr }))
+            }
+        }
+    }
+
+    /// `#[cfg(..)]` that holds in verification builds
+    pub fn cfg(&self) -> TokenStream2 {
+        let pred = &self.0;
+        quote! { #[cfg(#pred)] }
+    }
+
+    /// `#[cfg(..)]` that holds in all other builds
+    pub fn cfg_not(&self) -> TokenStream2 {
+        let pred = &self.0;
+        quote! { #[cfg(not(#pred))] }
+    }
+
+    /// Whether both predicates are written the same way
+    pub fn same_as(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl FromMeta for When {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Self::parse(expr).map_err(darling::Error::from)
+    }
+}
+
+/// Checks that `expr` is written like a cfg predicate
+fn check_predicate(expr: &Expr) -> syn::Result<()> {
+    match expr {
+        Expr::Path(p) if p.path.get_ident().is_some() => Ok(()),
+        Expr::Assign(assign)
+            if matches!(assign.left.as_ref(), Expr::Path(p) if p.path.get_ident().is_some())
+                && matches!(
+                    assign.right.as_ref(),
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(_),
+                        ..
+                    })
+                ) =>
+        {
+            Ok(())
+        }
+        Expr::Call(call)
+            if matches!(call.func.as_ref(), Expr::Path(p)
+                if p.path.is_ident("not") || p.path.is_ident("any") || p.path.is_ident("all")) =>
+        {
+            call.args.iter().try_for_each(check_predicate)
+        }
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected a feature name or a cfg predicate, as in `when = \"certora\"` or `when = not(test)`",
+        )),
+    }
+}
@@ -0,0 +1,85 @@
+use cvlr_macros::{cvlr_assert_that, cvlr_assume_that, cvlr_eval_that};
+pub fn test_forall() {
+    let balances = [1u64, 2, 3];
+    let cap = 3u64;
+    for i in 0..balances.len() {
+        {
+            let __cvlr_lhs = balances[i];
+            let __cvlr_rhs = cap;
+            cvlr::log::log_scope_start("assert");
+            ::cvlr_log::cvlr_log("_", &("balances [i] <= cap"));
+            ::cvlr_log::cvlr_log("balances [i]", &(__cvlr_lhs));
+            ::cvlr_log::cvlr_log("cap", &(__cvlr_rhs));
+            cvlr::log::log_scope_end("assert");
+            {
+                let c_ = __cvlr_lhs <= __cvlr_rhs;
+                ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
+                ::cvlr_asserts::cvlr_assert_checked(c_);
+            };
+        };
+    }
+    for i in 0..3 {
+        {
+            let __cvlr_lhs = balances[i];
+            let __cvlr_rhs = 0;
+            cvlr::log::log_scope_start("assume");
+            ::cvlr_log::cvlr_log("_", &("balances [i] > 0"));
+            ::cvlr_log::cvlr_log("balances [i]", &(__cvlr_lhs));
+            ::cvlr_log::cvlr_log("0", &(__cvlr_rhs));
+            cvlr::log::log_scope_end("assume");
+            ::cvlr_asserts::cvlr_assume_checked(__cvlr_lhs > __cvlr_rhs);
+        };
+    }
+    let _ = { ::core::iter::IntoIterator::into_iter(0..3).all(|i| balances[i] <= cap) };
+}
+pub fn test_exists() {
+    let orders = [5u64, 0, 7];
+    {
+        let c_ = ::core::iter::IntoIterator::into_iter(0..orders.len())
+            .any(|i| orders[i] == 0);
+        ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
+        ::cvlr_asserts::cvlr_assert_checked(c_);
+    };
+    ::cvlr_asserts::cvlr_assume_checked(
+        ::core::iter::IntoIterator::into_iter(0..3).any(|i| orders[i] == 0),
+    );
+    let _ = { ::core::iter::IntoIterator::into_iter(0..3).any(|i| orders[i] == 0) };
+}
+pub fn test_nested() {
+    let m = [[1u64, 2], [3, 4]];
+    for i in 0..2 {
+        for j in 0..2 {
+            {
+                let __cvlr_lhs = m[i][j];
+                let __cvlr_rhs = 0;
+                cvlr::log::log_scope_start("assert");
+                ::cvlr_log::cvlr_log("_", &("m [i] [j] > 0"));
+                ::cvlr_log::cvlr_log("m [i] [j]", &(__cvlr_lhs));
+                ::cvlr_log::cvlr_log("0", &(__cvlr_rhs));
+                cvlr::log::log_scope_end("assert");
+                {
+                    let c_ = __cvlr_lhs > __cvlr_rhs;
+                    ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
+                    ::cvlr_asserts::cvlr_assert_checked(c_);
+                };
+            };
+        }
+    }
+}
+pub fn test_connectives() {
+    let orders = [5u64, 0, 7];
+    {
+        let c_ = (orders.len() == 3)
+            && (::core::iter::IntoIterator::into_iter(0..3).any(|i| orders[i] == 0));
+        ::cvlr_asserts::log::add_loc("<FILE>", 0u32);
+        ::cvlr_asserts::cvlr_assert_checked(c_);
+    };
+    ::cvlr_asserts::cvlr_assume_checked(
+        !(::core::iter::IntoIterator::into_iter(0..3).all(|i| orders[i] > 0)),
+    );
+    let _ = {
+        (orders[0] == 0)
+            || (::core::iter::IntoIterator::into_iter(0..3).any(|i| orders[i] == 7))
+    };
+}
+pub fn main() {}
@@ -0,0 +1,34 @@
+use cvlr_macros::{cvlr_assert_that, cvlr_assume_that, cvlr_eval_that};
+
+pub fn test_forall() {
+    let balances = [1u64, 2, 3];
+    let cap = 3u64;
+
+    cvlr_assert_that!(forall!(i in 0..balances.len(), balances[i] <= cap));
+    cvlr_assume_that!(forall!(i in 0..3, balances[i] > 0));
+    let _ = cvlr_eval_that!(forall!(i in 0..3, balances[i] <= cap));
+}
+
+pub fn test_exists() {
+    let orders = [5u64, 0, 7];
+
+    cvlr_assert_that!(exists!(i in 0..orders.len(), orders[i] == 0));
+    cvlr_assume_that!(exists!(i in 0..3, orders[i] == 0));
+    let _ = cvlr_eval_that!(exists!(i in 0..3, orders[i] == 0));
+}
+
+pub fn test_nested() {
+    let m = [[1u64, 2], [3, 4]];
+
+    cvlr_assert_that!(forall!(i in 0..2, forall!(j in 0..2, m[i][j] > 0)));
+}
+
+pub fn test_connectives() {
+    let orders = [5u64, 0, 7];
+
+    cvlr_assert_that!(orders.len() == 3 && exists!(i in 0..3, orders[i] == 0));
+    cvlr_assume_that!(!forall!(i in 0..3, orders[i] > 0));
+    let _ = cvlr_eval_that!(orders[0] == 0 || (exists!(i in 0..3, orders[i] == 7)));
+}
+
+pub fn main() {}
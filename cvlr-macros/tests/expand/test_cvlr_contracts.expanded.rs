@@ -0,0 +1,15 @@
+use cvlr_macros::{ensures, requires};
+#[inline]
+pub fn withdraw(balance: u64, amount: u64) -> u64 {
+    balance - amount
+}
+pub struct Vault {
+    shares: u64,
+}
+impl Vault {
+    pub fn deposit(&mut self, amount: u64) -> Option<()> {
+        self.shares = self.shares.checked_add(amount)?;
+        Some(())
+    }
+}
+fn main() {}
@@ -0,0 +1,23 @@
+use cvlr_macros::{ensures, requires};
+
+// -- without the `certora` feature, functions with contracts are left as is
+#[requires(amount <= balance)]
+#[ensures(result == old(balance) - amount)]
+#[inline]
+pub fn withdraw(balance: u64, amount: u64) -> u64 {
+    balance - amount
+}
+
+pub struct Vault {
+    shares: u64,
+}
+
+impl Vault {
+    #[ensures(self.shares >= old(self.shares))]
+    pub fn deposit(&mut self, amount: u64) -> Option<()> {
+        self.shares = self.shares.checked_add(amount)?;
+        Some(())
+    }
+}
+
+fn main() {}
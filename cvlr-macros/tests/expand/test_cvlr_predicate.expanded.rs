@@ -3,7 +3,7 @@ pub struct Ctx {
     x: i32,
     y: i32,
 }
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 pub fn x_gt_zero(c: &Ctx) {
     c.x > 0;
 }
@@ -50,7 +50,7 @@ impl ::cvlr::spec::CvlrFormula for XGtZero {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for XGtZero {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn y_lt_hundred(c: &Ctx) {
     c.y < 100;
 }
@@ -97,7 +97,7 @@ impl ::cvlr::spec::CvlrFormula for YLtHundred {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for YLtHundred {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn multiple_conditions(c: &Ctx) {
     c.x > 0;
     c.y < 100;
@@ -170,7 +170,7 @@ impl ::cvlr::spec::CvlrFormula for MultipleConditions {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for MultipleConditions {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_let_statement(c: &Ctx) {
     let threshold = 0;
     c.x > threshold;
@@ -221,7 +221,7 @@ impl ::cvlr::spec::CvlrFormula for WithLetStatement {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithLetStatement {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_multiple_lets(c: &Ctx) {
     let min_x = 0;
     let max_y = 100;
@@ -302,7 +302,7 @@ impl ::cvlr::spec::CvlrFormula for WithMultipleLets {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithMultipleLets {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn let_before_expressions(c: &Ctx) {
     let threshold = 5;
     let limit = 100;
@@ -409,7 +409,7 @@ impl ::cvlr::spec::CvlrFormula for LetBeforeExpressions {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for LetBeforeExpressions {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_if_else(c: &Ctx) {
     if c.x > 0 { c.y > 0 } else { c.y < 0 };
 }
@@ -451,7 +451,7 @@ impl ::cvlr::spec::CvlrFormula for WithIfElse {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithIfElse {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_if_else_true(c: &Ctx) {
     if c.x > 0 { c.y > 0 } else { true };
 }
@@ -489,7 +489,7 @@ impl ::cvlr::spec::CvlrFormula for WithIfElseTrue {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithIfElseTrue {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_if_else_both_true(c: &Ctx) {
     if c.x > 0 { true } else { true };
 }
@@ -514,7 +514,7 @@ impl ::cvlr::spec::CvlrFormula for WithIfElseBothTrue {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithIfElseBothTrue {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn with_nested_if_else(c: &Ctx) {
     if c.x > 0 { if c.y > 0 { c.x + c.y > 0 } else { c.x > c.y } } else { c.y < 0 };
 }
@@ -574,7 +574,7 @@ impl ::cvlr::spec::CvlrFormula for WithNestedIfElse {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for WithNestedIfElse {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn multiple_if_else(c: &Ctx) {
     if c.x > 0 { c.y > 0 } else { c.y < 0 };
     if c.x < 100 { c.y < 100 } else { c.y > 100 };
@@ -637,7 +637,7 @@ impl ::cvlr::spec::CvlrFormula for MultipleIfElse {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for MultipleIfElse {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn if_else_with_let(c: &Ctx) {
     let threshold = 0;
     if c.x > threshold { c.y > threshold } else { c.y < threshold };
@@ -683,7 +683,7 @@ impl ::cvlr::spec::CvlrFormula for IfElseWithLet {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for IfElseWithLet {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn if_else_with_multiple_lets(c: &Ctx) {
     let min_val = 0;
     let max_val = 100;
@@ -754,7 +754,7 @@ impl ::cvlr::spec::CvlrFormula for IfElseWithMultipleLets {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for IfElseWithMultipleLets {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn x_increased(c: &Ctx, old: &Ctx) {
     c.x > old.x;
 }
@@ -846,7 +846,7 @@ impl ::cvlr::spec::CvlrFormula for XIncreased {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for XIncreased {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn both_increased(c: &Ctx, old: &Ctx) {
     c.x > old.x;
     c.y > old.y;
@@ -964,7 +964,7 @@ impl ::cvlr::spec::CvlrFormula for BothIncreased {
     }
 }
 impl ::cvlr::spec::CvlrPredicate for BothIncreased {}
-#[allow(unused_must_use, dead_code)]
+#[allow(unused_must_use, dead_code, clippy::no_effect)]
 fn x_increased_with_let(c: &Ctx, old: &Ctx) {
     let threshold = 0;
     c.x > old.x + threshold;
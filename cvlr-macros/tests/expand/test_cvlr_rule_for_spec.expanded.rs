@@ -1,6 +1,16 @@
 use cvlr_macros::cvlr_rule_for_spec;
 pub fn test_basic_with_base_prefix() {
     let expr = true;
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "solvency_update_exchange_price_no_interest_free_new",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["solvency"],
+        };
+    };
     {
         let _rule_name = "solvency_update_exchange_price_no_interest_free_new";
         let _spec = expr;
@@ -9,6 +19,16 @@ pub fn test_basic_with_base_prefix() {
 }
 pub fn test_without_base_prefix() {
     let expr = false;
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "liquidity_update_price",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["liquidity"],
+        };
+    };
     {
         let _rule_name = "liquidity_update_price";
         let _spec = expr;
@@ -17,16 +37,46 @@ pub fn test_without_base_prefix() {
 }
 pub fn test_name_conversion() {
     let expr = 42;
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "my_rule_name_test_function",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["My Rule Name"],
+        };
+    };
     {
         let _rule_name = "my_rule_name_test_function";
         let _spec = expr;
         let _base = "base_test_function";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "test_rule_name_another_function",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["test-rule-name"],
+        };
+    };
     {
         let _rule_name = "test_rule_name_another_function";
         let _spec = expr;
         let _base = "base_another_function";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "already_snake_case_simple_func",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["already_snake_case"],
+        };
+    };
     {
         let _rule_name = "already_snake_case_simple_func";
         let _spec = expr;
@@ -35,11 +85,31 @@ pub fn test_name_conversion() {
 }
 pub fn test_complex_spec_expressions() {
     let complex_expr = || { true };
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "complex_handler",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["complex"],
+        };
+    };
     {
         let _rule_name = "complex_handler";
         let _spec = complex_expr();
         let _base = "base_handler";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "nested_calculator",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["nested"],
+        };
+    };
     {
         let _rule_name = "nested_calculator";
         let _spec = (1 + 2) * 3;
@@ -48,6 +118,16 @@ pub fn test_complex_spec_expressions() {
 }
 pub fn test_trailing_comma() {
     let expr = true;
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "trailing_test",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["trailing"],
+        };
+    };
     {
         let _rule_name = "trailing_test";
         let _spec = expr;
@@ -56,16 +136,46 @@ pub fn test_trailing_comma() {
 }
 pub fn test_edge_cases() {
     let expr = 0;
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "x_func",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["x"],
+        };
+    };
     {
         let _rule_name = "x_func";
         let _spec = expr;
         let _base = "base_func";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "very_long_rule_name_that_should_work_very_long_function_name_that_should_also_work",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["very_long_rule_name_that_should_work"],
+        };
+    };
     {
         let _rule_name = "very_long_rule_name_that_should_work_very_long_function_name_that_should_also_work";
         let _spec = expr;
         let _base = "base_very_long_function_name_that_should_also_work";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "rule123_test456",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["rule123"],
+        };
+    };
     {
         let _rule_name = "rule123_test456";
         let _spec = expr;
@@ -74,11 +184,31 @@ pub fn test_edge_cases() {
 }
 pub fn test_spec_with_method_calls() {
     let expr = [1, 2, 3];
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "method_check",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["method"],
+        };
+    };
     {
         let _rule_name = "method_check";
         let _spec = expr.len() > 0;
         let _base = "base_check";
     }
+    const _: () = {
+        #[used]
+        #[link_section = "cvlr_rules"]
+        static __CVLR_RULE: ::cvlr::manifest::RuleEntry = ::cvlr::manifest::RuleEntry {
+            name: "chained_sum",
+            file: "<FILE>",
+            line: 0u32,
+            tags: &["chained"],
+        };
+    };
     {
         let _rule_name = "chained_sum";
         let _spec = expr.iter().sum::<i32>() > 0;
@@ -0,0 +1,19 @@
+use cvlr_macros::mock_fn;
+fn mock_scale(_: &Pool, x: u64, _: (u8, u8)) -> u64 {
+    x
+}
+fn mock_len<T>(items: &[T]) -> usize {
+    items.len()
+}
+pub struct Pool {
+    factor: u64,
+}
+impl Pool {
+    pub fn scale(&self, x: u64, __cvlr_arg_2: (u8, u8)) -> u64 {
+        mock_scale(self, x, __cvlr_arg_2)
+    }
+}
+pub fn len<T: Clone>(items: &[T]) -> usize {
+    mock_len(items)
+}
+fn main() {}
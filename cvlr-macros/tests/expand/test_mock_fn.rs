@@ -0,0 +1,29 @@
+use cvlr_macros::mock_fn;
+
+// -- `when = not(..)` holds here, so the mocks are in place
+fn mock_scale(_: &Pool, x: u64, _: (u8, u8)) -> u64 {
+    x
+}
+
+fn mock_len<T>(items: &[T]) -> usize {
+    items.len()
+}
+
+pub struct Pool {
+    factor: u64,
+}
+
+impl Pool {
+    #[mock_fn(with = mock_scale, when = not(feature = "certora"))]
+    pub fn scale(&self, mut x: u64, (lo, hi): (u8, u8)) -> u64 {
+        x *= self.factor;
+        x + lo as u64 + hi as u64
+    }
+}
+
+#[mock_fn(with = mock_len, when = not(feature = "certora"))]
+pub fn len<T: Clone>(items: &[T]) -> usize {
+    items.to_vec().len()
+}
+
+fn main() {}
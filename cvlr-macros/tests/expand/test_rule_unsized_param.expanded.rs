@@ -0,0 +1,2 @@
+use cvlr_macros::rule;
+fn main() {}
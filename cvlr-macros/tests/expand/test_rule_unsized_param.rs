@@ -0,0 +1,13 @@
+use cvlr_macros::rule;
+
+#[rule]
+fn rule_bytes(data: &[u8]) {
+    let _ = data;
+}
+
+#[rule]
+fn rule_name(name: &str) {
+    let _ = name;
+}
+
+fn main() {}
@@ -12,6 +12,7 @@ fn test_cvlr_assert_that_compiles() {
     t.pass("tests/expand/test_cvlr_assert_that_guarded_comparisons.rs");
     t.pass("tests/expand/test_cvlr_assert_that_booleans.rs");
     t.pass("tests/expand/test_cvlr_assert_that_true_and_if.rs");
+    t.pass("tests/expand/test_cvlr_assert_that_quantifiers.rs");
     t.pass("tests/expand/test_cvlr_assert_all.rs");
     t.pass("tests/expand/test_cvlr_assume_that.rs");
     t.pass("tests/expand/test_cvlr_assume_that_true_and_if.rs");
@@ -20,4 +21,7 @@ fn test_cvlr_assert_that_compiles() {
     t.pass("tests/expand/test_cvlr_eval_all.rs");
     t.pass("tests/expand/test_cvlr_rule_for_spec.rs");
     t.pass("tests/expand/test_cvlr_predicate.rs");
+    t.pass("tests/expand/test_cvlr_contracts.rs");
+    t.pass("tests/expand/test_mock_fn.rs");
+    t.compile_fail("tests/expand/test_rule_unsized_param.rs");
 }
@@ -1,4 +1,5 @@
 //! Runtime tests for cvlr_predicate macro
+#![allow(clippy::unused_unit, clippy::if_same_then_else, clippy::needless_bool)]
 
 use cvlr::spec::CvlrFormula;
 use cvlr_macros::cvlr_predicate;
@@ -251,3 +252,49 @@ fn test_two_state_predicate() {
     let post3 = Ctx { x: 2, y: 10 };
     assert!(!pred4.eval_with_states(&post3, &pre)); // x increased by only 1, not > 1
 }
+
+pub struct Vault {
+    balances: [u64; 3],
+    cap: u64,
+}
+
+#[cvlr_predicate]
+fn balances_capped(v: &Vault) {
+    forall!(i in 0..v.balances.len(), v.balances[i] <= v.cap);
+}
+
+#[cvlr_predicate]
+fn has_empty_balance(v: &Vault) {
+    v.cap > 0;
+    exists!(i in 0..v.balances.len(), v.balances[i] == 0);
+}
+
+#[test]
+fn test_quantified_predicate() {
+    let v = Vault {
+        balances: [0, 2, 3],
+        cap: 3,
+    };
+    assert!(BalancesCapped.eval(&v));
+    assert!(HasEmptyBalance.eval(&v));
+    BalancesCapped.assert(&v);
+    HasEmptyBalance.assert(&v);
+    BalancesCapped.assume(&v);
+
+    let v2 = Vault {
+        balances: [1, 4, 3],
+        cap: 3,
+    };
+    assert!(!BalancesCapped.eval(&v2));
+    assert!(!HasEmptyBalance.eval(&v2));
+}
+
+#[test]
+#[should_panic]
+fn test_quantified_predicate_assert_fails() {
+    let v = Vault {
+        balances: [1, 4, 3],
+        cap: 3,
+    };
+    BalancesCapped.assert(&v);
+}
@@ -41,6 +41,12 @@ mod rt_decls {
         pub fn CVT_nativeint_u64_u128_max() -> u64;
         pub fn CVT_nativeint_u64_u256_max() -> u64;
     }
+
+    #[allow(improper_ctypes)]
+    extern "C" {
+        // duplicated to avoid cvlr-mathint depend on cvlr-log
+        pub fn CVT_calltrace_print_u64_1(tag: &str, x: u64);
+    }
 }
 
 /// Run-time implementation of the external library
@@ -101,10 +107,9 @@ mod rt_impls {
 
     #[no_mangle]
     pub extern "C" fn CVT_nativeint_u64_nondet() -> u64 {
-        // -- concrete implementation returns some specific number
-        // -- it can, potentially, return a random number instead, or depend on
-        // -- run-time of nondet
-        0
+        // -- concrete implementation returns 0, unless the nondet runtime is
+        // -- seeded or replaying a counterexample
+        cvlr_nondet::rt::next_for(cvlr_nondet::rt::ValueKind::NativeInt).map_or(0, |v| v as u64)
     }
 
     #[no_mangle]
@@ -135,13 +140,11 @@ mod rt_impls {
 
     #[no_mangle]
     pub extern "C" fn CVT_nativeint_u64_u128_max() -> u64 {
-        assert!(false, "u128_max is not supported");
         todo!();
     }
 
     #[no_mangle]
     pub extern "C" fn CVT_nativeint_u64_u256_max() -> u64 {
-        assert!(false, "u256_max is not supported");
         todo!();
     }
 
@@ -191,6 +194,7 @@ mod rt_impls {
     }
 }
 
+use core::ops::{Bound, RangeBounds};
 use rt_decls::*;
 
 impl NativeIntU64 {
@@ -267,6 +271,37 @@ impl NativeIntU64 {
         cvlr_nondet::nondet()
     }
 
+    /// Returns a nondet value within the given bounds
+    ///
+    /// Unlike [cvlr_nondet::nondet_in], the chosen value is not logged
+    pub fn nondet_in_bounds(start: Bound<Self>, end: Bound<Self>) -> Self {
+        #[cfg(not(feature = "rt"))]
+        let v = Self::nondet();
+        #[cfg(feature = "rt")]
+        let v = {
+            let lo = match start {
+                Bound::Included(x) => Some(x.0),
+                Bound::Excluded(x) => x.0.checked_add(1),
+                Bound::Unbounded => Some(0),
+            };
+            let hi = match end {
+                Bound::Included(x) => Some(x.0),
+                Bound::Excluded(x) => x.0.checked_sub(1),
+                Bound::Unbounded => Some(u64::MAX),
+            };
+            match (lo, hi) {
+                (Some(lo), Some(hi)) if lo <= hi => {
+                    let raw = Self::nondet().0 as u128;
+                    Self(cvlr_nondet::__private::rt_wrap_into(raw, lo as u128, hi as u128) as u64)
+                }
+                // -- empty range, the assumption below fails
+                _ => Self::nondet(),
+            }
+        };
+        cvlr_asserts::cvlr_assume!((start, end).contains(&v));
+        v
+    }
+
     pub fn checked_sub(&self, v: NativeIntU64) -> Self {
         *self - v
     }
@@ -575,6 +610,16 @@ impl cvlr_nondet::Nondet for NativeIntU64 {
     }
 }
 
+impl cvlr_nondet::NondetRange for NativeIntU64 {
+    fn nondet_in<R: RangeBounds<Self>>(range: R) -> Self {
+        let v = Self::nondet_in_bounds(range.start_bound().cloned(), range.end_bound().cloned());
+        unsafe {
+            CVT_calltrace_print_u64_1(cvlr_nondet::NONDET_IN_TAG, v.0);
+        }
+        v
+    }
+}
+
 macro_rules! impl_is_uint {
     ($name:ident, $uint:ty, $is_uint:ident) => {
         pub fn $name(v: $uint) -> bool {
@@ -630,6 +675,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn test_comparison_operations() {
         let a: NativeIntU64 = 5.into();
         let b: NativeIntU64 = 10.into();
@@ -0,0 +1,61 @@
+use std::{string::String, vec::Vec};
+
+use crate::{havoc::memhavoc, nondet, Nondet};
+
+/// Returns a nondet length that is at most `max_len`
+#[inline(always)]
+fn nondet_len(max_len: usize) -> usize {
+    let len = nondet::<usize>();
+    #[cfg(feature = "rt")]
+    let len = crate::range::rt_wrap_into(len as u128, 0, max_len as u128) as usize;
+    cvlr_asserts::cvlr_assume!(len <= max_len);
+    len
+}
+
+/// Return a vector of nondet length at most `max_len` with nondet elements
+///
+/// # Example
+///
+/// ```
+/// use cvlr::nondet::nondet_vec;
+/// let v: Vec<u64> = nondet_vec(4);
+/// assert!(v.len() <= 4);
+/// ```
+pub fn nondet_vec<T: Nondet>(max_len: usize) -> Vec<T> {
+    let len = nondet_len(max_len);
+    let mut v = Vec::with_capacity(len);
+    for _ in 0..len {
+        v.push(nondet::<T>());
+    }
+    v
+}
+
+/// Return a byte vector of nondet length at most `max_len` with nondet contents
+///
+/// Contents are havoced in one step with [memhavoc] instead of element by element.
+pub fn nondet_bytes(max_len: usize) -> Vec<u8> {
+    let len = nondet_len(max_len);
+    let mut v = Vec::<u8>::with_capacity(len);
+    unsafe {
+        memhavoc(v.as_mut_ptr(), len);
+        v.set_len(len);
+    }
+    v
+}
+
+/// Return a string of nondet length at most `max_len` bytes
+///
+/// The contents are nondet, but restricted to ASCII characters.
+pub fn nondet_string(max_len: usize) -> String {
+    let bytes = nondet_bytes(max_len);
+    // -- rt values are not symbolic, clear the high bit of every byte
+    #[cfg(feature = "rt")]
+    let bytes = {
+        let mut bytes = bytes;
+        bytes.iter_mut().for_each(|b| *b &= 0x7f);
+        bytes
+    };
+    cvlr_asserts::cvlr_assume!(bytes.is_ascii());
+    // SAFETY: ASCII bytes are valid UTF-8
+    unsafe { String::from_utf8_unchecked(bytes) }
+}
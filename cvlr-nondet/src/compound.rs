@@ -0,0 +1,51 @@
+use crate::{nondet, Nondet};
+
+impl<T: Nondet, const N: usize> Nondet for [T; N] {
+    #[inline]
+    fn nondet() -> [T; N] {
+        ::core::array::from_fn(|_| nondet::<T>())
+    }
+}
+
+impl<T: Nondet, E: Nondet> Nondet for Result<T, E> {
+    #[inline]
+    fn nondet() -> Result<T, E> {
+        if nondet::<bool>() {
+            Ok(nondet::<T>())
+        } else {
+            Err(nondet::<E>())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Nondet> Nondet for std::boxed::Box<T> {
+    #[inline]
+    fn nondet() -> std::boxed::Box<T> {
+        std::boxed::Box::new(nondet::<T>())
+    }
+}
+
+macro_rules! impl_nondet_tuple {
+    ($($t:ident),+) => {
+        impl<$($t: Nondet),+> Nondet for ($($t,)+) {
+            #[inline]
+            fn nondet() -> ($($t,)+) {
+                ($(nondet::<$t>(),)+)
+            }
+        }
+    };
+}
+
+impl_nondet_tuple! { A }
+impl_nondet_tuple! { A, B }
+impl_nondet_tuple! { A, B, C }
+impl_nondet_tuple! { A, B, C, D }
+impl_nondet_tuple! { A, B, C, D, E }
+impl_nondet_tuple! { A, B, C, D, E, F }
+impl_nondet_tuple! { A, B, C, D, E, F, G }
+impl_nondet_tuple! { A, B, C, D, E, F, G, H }
+impl_nondet_tuple! { A, B, C, D, E, F, G, H, I }
+impl_nondet_tuple! { A, B, C, D, E, F, G, H, I, J }
+impl_nondet_tuple! { A, B, C, D, E, F, G, H, I, J, K }
+impl_nondet_tuple! { A, B, C, D, E, F, G, H, I, J, K, L }
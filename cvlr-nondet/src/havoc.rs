@@ -9,10 +9,10 @@ mod rt_decls {
 #[cfg(feature = "rt")]
 #[allow(dead_code)]
 mod rt_imps {
+    #[no_mangle]
     pub extern "C" fn memhavoc_c(data: *mut u8, sz: usize) {
-        unsafe {
-            data.write_bytes(0, sz);
-        }
+        let buf = unsafe { core::slice::from_raw_parts_mut(data, sz) };
+        crate::rt::fill_bytes(buf);
     }
 }
 
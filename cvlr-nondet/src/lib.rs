@@ -3,14 +3,31 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(feature = "std")]
+mod collections;
+mod compound;
 mod core;
 mod option;
+mod range;
 mod scalars;
 
 #[cfg(feature = "std")]
 pub mod havoc;
 
+#[cfg(feature = "rt")]
+pub mod rt;
+
 pub use core::{nondet, nondet_with, Nondet};
 
+#[cfg(feature = "std")]
+pub use collections::{nondet_bytes, nondet_string, nondet_vec};
+
 pub use option::nondet_option;
+pub use range::{nondet_in, nondet_in_range, NondetRange, NONDET_IN_TAG};
+
+/// Helpers for the `rt` implementations of other cvlr crates
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::range::rt_wrap_into;
+}
 pub use scalars::{cvlr_nondet_small_i128, cvlr_nondet_small_u128};
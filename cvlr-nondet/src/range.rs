@@ -0,0 +1,116 @@
+use core::ops::{RangeBounds, RangeInclusive};
+
+use crate::{nondet, Nondet};
+
+mod rt_decls {
+    #[allow(improper_ctypes)]
+    extern "C" {
+        // duplicated to avoid cvlr-nondet depend on cvlr-log
+        pub fn CVT_calltrace_print_u64_1(tag: &str, x: u64);
+        pub fn CVT_calltrace_print_i64_1(tag: &str, x: i64);
+        pub fn CVT_calltrace_print_u128(tag: &str, x: u128);
+        pub fn CVT_calltrace_print_i128(tag: &str, x: i128);
+    }
+}
+
+/// Calltrace tag under which values chosen by [NondetRange::nondet_in] are logged
+pub const NONDET_IN_TAG: &str = "nondet_in";
+
+/// A trait for types whose non-deterministic values can be restricted to a range
+///
+/// Symbolically, a nondet value is created and assumed to be in the range. The
+/// `rt` implementation picks a concrete value inside the range instead, so that
+/// the assumption is not violated at run-time.
+pub trait NondetRange: Nondet {
+    /// Returns a nondet value in `range` and logs it in the calltrace
+    fn nondet_in<R: RangeBounds<Self>>(range: R) -> Self;
+}
+
+/// Return a nondet value in the given range
+///
+/// # Example
+///
+/// ```
+/// use cvlr::nondet::nondet_in;
+/// let x: u64 = nondet_in(10..20);
+/// assert!((10..20).contains(&x));
+/// ```
+pub fn nondet_in<T: NondetRange, R: RangeBounds<T>>(range: R) -> T {
+    T::nondet_in(range)
+}
+
+/// Return a nondet value in the inclusive range `lo..=hi`
+pub fn nondet_in_range<T: NondetRange>(range: RangeInclusive<T>) -> T {
+    T::nondet_in(range)
+}
+
+/// Maps `raw` into the inclusive range `[lo, hi]` of unsigned `u128` values
+///
+/// Used by `rt` implementations to turn an arbitrary concrete value into one
+/// that satisfies the range assumption. Values already in range are kept, so
+/// that replayed values are not changed.
+#[inline(always)]
+pub fn rt_wrap_into(raw: u128, lo: u128, hi: u128) -> u128 {
+    let span = hi.wrapping_sub(lo).wrapping_add(1);
+    if span == 0 || (lo <= raw && raw <= hi) {
+        raw
+    } else {
+        lo.wrapping_add(raw % span)
+    }
+}
+
+macro_rules! impl_nondet_range {
+    ($t:ty, $ut:ty, $log:ident, $log_ty:ty) => {
+        impl NondetRange for $t {
+            fn nondet_in<R: RangeBounds<Self>>(range: R) -> Self {
+                #[cfg(not(feature = "rt"))]
+                let v: $t = nondet();
+                #[cfg(feature = "rt")]
+                let v: $t = {
+                    let lo = match range.start_bound() {
+                        core::ops::Bound::Included(x) => Some(*x),
+                        core::ops::Bound::Excluded(x) => x.checked_add(1),
+                        core::ops::Bound::Unbounded => Some(<$t>::MIN),
+                    };
+                    let hi = match range.end_bound() {
+                        core::ops::Bound::Included(x) => Some(*x),
+                        core::ops::Bound::Excluded(x) => x.checked_sub(1),
+                        core::ops::Bound::Unbounded => Some(<$t>::MAX),
+                    };
+                    match (lo, hi) {
+                        (Some(lo), Some(hi)) if lo <= hi => {
+                            // -- shift signed values so that the order of the
+                            // -- unsigned representation matches the signed order
+                            let bias = <$t>::MIN as $ut;
+                            let raw = (nondet::<$t>() as $ut).wrapping_sub(bias);
+                            let lo = (lo as $ut).wrapping_sub(bias);
+                            let hi = (hi as $ut).wrapping_sub(bias);
+                            let v = rt_wrap_into(raw as u128, lo as u128, hi as u128);
+                            (v as $ut).wrapping_add(bias) as $t
+                        }
+                        // -- empty range, the assumption below fails
+                        _ => nondet(),
+                    }
+                };
+                cvlr_asserts::cvlr_assume!(range.contains(&v));
+                unsafe {
+                    rt_decls::$log(NONDET_IN_TAG, v as $log_ty);
+                }
+                v
+            }
+        }
+    };
+}
+
+impl_nondet_range! { u8, u8, CVT_calltrace_print_u64_1, u64 }
+impl_nondet_range! { u16, u16, CVT_calltrace_print_u64_1, u64 }
+impl_nondet_range! { u32, u32, CVT_calltrace_print_u64_1, u64 }
+impl_nondet_range! { u64, u64, CVT_calltrace_print_u64_1, u64 }
+impl_nondet_range! { usize, usize, CVT_calltrace_print_u64_1, u64 }
+impl_nondet_range! { u128, u128, CVT_calltrace_print_u128, u128 }
+
+impl_nondet_range! { i8, u8, CVT_calltrace_print_i64_1, i64 }
+impl_nondet_range! { i16, u16, CVT_calltrace_print_i64_1, i64 }
+impl_nondet_range! { i32, u32, CVT_calltrace_print_i64_1, i64 }
+impl_nondet_range! { i64, u64, CVT_calltrace_print_i64_1, i64 }
+impl_nondet_range! { i128, u128, CVT_calltrace_print_i128, i128 }
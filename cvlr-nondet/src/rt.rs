@@ -0,0 +1,231 @@
+//! Source of concrete nondet values under the `rt` feature
+//!
+//! By default, every nondet value is `Default::default()` and havoced memory is
+//! zero-filled. Once a seed is set, either with [set_seed] or through the
+//! `CVLR_SEED` environment variable, values are drawn from a deterministic
+//! pseudo-random generator instead, so that a run is reproduced by its seed.
+//!
+//! With `std`, values can also be replayed from a counterexample file, see
+//! [replay_file]. A replay takes precedence over the seed.
+//!
+//! With `std`, the runtime state is per thread, so that tests running in
+//! parallel do not interfere with each other.
+
+#[cfg(feature = "std")]
+mod replay;
+
+#[cfg(feature = "std")]
+pub use replay::{
+    clear_replay, replay_file, replay_remaining, replay_str, ReplayError, REPLAY_ENV_VAR,
+};
+
+/// Environment variable from which the seed is read (`std` only)
+pub const SEED_ENV_VAR: &str = "CVLR_SEED";
+
+/// Kind of a concrete nondet value, named as in a replay file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    NativeInt,
+    Bytes,
+}
+
+impl ValueKind {
+    const ALL: [ValueKind; 13] = [
+        ValueKind::U8,
+        ValueKind::U16,
+        ValueKind::U32,
+        ValueKind::U64,
+        ValueKind::Usize,
+        ValueKind::U128,
+        ValueKind::I8,
+        ValueKind::I16,
+        ValueKind::I32,
+        ValueKind::I64,
+        ValueKind::I128,
+        ValueKind::NativeInt,
+        ValueKind::Bytes,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ValueKind::U8 => "u8",
+            ValueKind::U16 => "u16",
+            ValueKind::U32 => "u32",
+            ValueKind::U64 => "u64",
+            ValueKind::Usize => "usize",
+            ValueKind::U128 => "u128",
+            ValueKind::I8 => "i8",
+            ValueKind::I16 => "i16",
+            ValueKind::I32 => "i32",
+            ValueKind::I64 => "i64",
+            ValueKind::I128 => "i128",
+            ValueKind::NativeInt => "nativeint",
+            ValueKind::Bytes => "bytes",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ValueKind> {
+        Self::ALL.into_iter().find(|k| k.name() == name)
+    }
+
+    fn is_wide(self) -> bool {
+        matches!(self, ValueKind::U128 | ValueKind::I128)
+    }
+}
+
+impl core::fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// SplitMix64 generator
+#[derive(Clone, Copy)]
+struct Rng {
+    seed: Option<u64>,
+    state: u64,
+}
+
+impl Rng {
+    const UNSEEDED: Rng = Rng {
+        seed: None,
+        state: 0,
+    };
+
+    fn seeded(seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            state: seed,
+        }
+    }
+
+    fn next(&mut self) -> Option<u64> {
+        self.seed?;
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Some(z ^ (z >> 31))
+    }
+}
+
+#[cfg(feature = "std")]
+mod state {
+    use super::Rng;
+    use std::cell::Cell;
+
+    std::thread_local! {
+        static RNG: Cell<Option<Rng>> = const { Cell::new(None) };
+    }
+
+    fn from_env() -> Rng {
+        std::env::var(super::SEED_ENV_VAR)
+            .ok()
+            .and_then(|s| super::parse_seed(&s))
+            .map_or(Rng::UNSEEDED, Rng::seeded)
+    }
+
+    pub fn with<R>(f: impl FnOnce(&mut Rng) -> R) -> R {
+        RNG.with(|cell| {
+            let mut rng = cell.get().unwrap_or_else(from_env);
+            let res = f(&mut rng);
+            cell.set(Some(rng));
+            res
+        })
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod state {
+    use super::Rng;
+    use core::cell::Cell;
+
+    struct GlobalRng(Cell<Rng>);
+    // -- without std there are no threads to share the generator with
+    unsafe impl Sync for GlobalRng {}
+
+    static RNG: GlobalRng = GlobalRng(Cell::new(Rng::UNSEEDED));
+
+    pub fn with<R>(f: impl FnOnce(&mut Rng) -> R) -> R {
+        let mut rng = RNG.0.get();
+        let res = f(&mut rng);
+        RNG.0.set(rng);
+        res
+    }
+}
+
+/// Parses a seed given either in decimal or in hex with a `0x` prefix
+pub fn parse_seed(s: &str) -> Option<u64> {
+    let s = s.trim();
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+/// Seed the generator, overriding `CVLR_SEED`
+pub fn set_seed(seed: u64) {
+    state::with(|rng| *rng = Rng::seeded(seed));
+}
+
+/// Remove the seed, so that nondet values are `Default::default()` again
+pub fn clear_seed() {
+    state::with(|rng| *rng = Rng::UNSEEDED);
+}
+
+/// The seed in use, if any
+pub fn seed() -> Option<u64> {
+    state::with(|rng| rng.seed)
+}
+
+/// Next pseudo-random value, or `None` if no seed is set
+pub fn next_u64() -> Option<u64> {
+    state::with(|rng| rng.next())
+}
+
+/// Next concrete value of the given kind, or `None` if it should be the default
+///
+/// The value is returned as its bit pattern, to be cast to the requested type.
+/// Panics if a replay is active and the next value does not match `kind`.
+pub fn next_for(kind: ValueKind) -> Option<u128> {
+    #[cfg(feature = "std")]
+    if let Some(v) = replay::next_int(kind) {
+        return Some(v);
+    }
+    state::with(|rng| {
+        let lo = rng.next()? as u128;
+        if kind.is_wide() {
+            let hi = rng.next()? as u128;
+            Some((hi << 64) | lo)
+        } else {
+            Some(lo)
+        }
+    })
+}
+
+/// Fills `buf` with the next bytes, or zeros if no seed is set
+///
+/// Panics if a replay is active and the next value is not a matching byte string.
+pub fn fill_bytes(buf: &mut [u8]) {
+    #[cfg(feature = "std")]
+    if replay::fill_bytes(buf) {
+        return;
+    }
+    state::with(|rng| {
+        for chunk in buf.chunks_mut(8) {
+            let v = rng.next().unwrap_or(0).to_le_bytes();
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+    });
+}
@@ -0,0 +1,292 @@
+//! Replay of counterexample values
+//!
+//! A counterexample file lists the concrete values of nondet calls, one per
+//! line, in the order in which they are made. Each line is a value kind
+//! followed by the value:
+//!
+//! ```text
+//! # comments and blank lines are ignored
+//! u64 42
+//! i32 -7
+//! u128 0xffff_ffff_ffff_ffff_ffff
+//! nativeint 1000
+//! bytes 00ff10
+//! ```
+//!
+//! Kinds are `u8`, `u16`, `u32`, `u64`, `usize`, `u128`, `i8`, `i16`, `i32`,
+//! `i64`, `i128`, `nativeint`, and `bytes`. Integers are decimal or hex with a
+//! `0x` prefix, and may contain `_`. A `bytes` value is a hex string, and is
+//! consumed by a single `memhavoc` call of the same length. Derived values use
+//! the kind they are built from: `bool` is a `u64` and `char` is a `u32`.
+//!
+//! Running past the end of the file, or requesting a kind different from the
+//! next one in the file, panics with the position of the offending entry.
+
+use std::{
+    cell::RefCell,
+    fmt, format,
+    path::{Path, PathBuf},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::ValueKind;
+
+/// Environment variable from which a counterexample file is replayed
+pub const REPLAY_ENV_VAR: &str = "CVLR_REPLAY";
+
+/// Error while loading a counterexample file
+#[derive(Debug)]
+pub enum ReplayError {
+    Io { path: PathBuf, err: std::io::Error },
+    Parse { line: usize, msg: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Io { path, err } => {
+                write!(f, "cannot read counterexample {}: {}", path.display(), err)
+            }
+            ReplayError::Parse { line, msg } => {
+                write!(f, "malformed counterexample at line {}: {}", line, msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+enum Value {
+    Int(u128),
+    Bytes(Vec<u8>),
+}
+
+struct Entry {
+    kind: ValueKind,
+    value: Value,
+    line: usize,
+}
+
+struct Replay {
+    entries: Vec<Entry>,
+    next: usize,
+}
+
+enum State {
+    Uninit,
+    Off,
+    On(Replay),
+}
+
+std::thread_local! {
+    static REPLAY: RefCell<State> = const { RefCell::new(State::Uninit) };
+}
+
+fn parse_int(kind: ValueKind, s: &str) -> Result<u128, String> {
+    let s = s.replace('_', "");
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(d) => (true, d),
+        None => (false, s.as_str()),
+    };
+    let magnitude = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => digits.parse::<u128>(),
+    }
+    .map_err(|_| format!("`{}` is not a number", s))?;
+
+    let (min, max): (i128, u128) = match kind {
+        ValueKind::U8 => (0, u8::MAX as u128),
+        ValueKind::U16 => (0, u16::MAX as u128),
+        ValueKind::U32 => (0, u32::MAX as u128),
+        ValueKind::U64 | ValueKind::NativeInt => (0, u64::MAX as u128),
+        ValueKind::Usize => (0, usize::MAX as u128),
+        ValueKind::U128 => (0, u128::MAX),
+        ValueKind::I8 => (i8::MIN as i128, i8::MAX as u128),
+        ValueKind::I16 => (i16::MIN as i128, i16::MAX as u128),
+        ValueKind::I32 => (i32::MIN as i128, i32::MAX as u128),
+        ValueKind::I64 => (i64::MIN as i128, i64::MAX as u128),
+        ValueKind::I128 => (i128::MIN, i128::MAX as u128),
+        ValueKind::Bytes => unreachable!(),
+    };
+    let in_range = if neg {
+        magnitude <= min.unsigned_abs()
+    } else {
+        magnitude <= max
+    };
+    if !in_range {
+        return Err(format!("`{}` is out of range for {}", s, kind));
+    }
+    Ok(if neg {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    })
+}
+
+fn parse_bytes(s: &str) -> Result<Vec<u8>, String> {
+    let s = s.replace('_', "");
+    if !s.is_ascii() {
+        return Err(format!("`{}` is not hex", s));
+    }
+    if !s.len().is_multiple_of(2) {
+        return Err(format!("`{}` has an odd number of hex digits", s));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| format!("`{}` is not hex", s)))
+        .collect()
+}
+
+fn parse(src: &str) -> Result<Replay, ReplayError> {
+    let mut entries = Vec::new();
+    for (idx, text) in src.lines().enumerate() {
+        let line = idx + 1;
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        let err = |msg: String| ReplayError::Parse { line, msg };
+        let (kind, value) = text
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| err("expected `<kind> <value>`".to_string()))?;
+        let kind = ValueKind::from_name(kind)
+            .ok_or_else(|| err(format!("unknown value kind `{}`", kind)))?;
+        let value = value.trim();
+        let value = if kind == ValueKind::Bytes {
+            Value::Bytes(parse_bytes(value).map_err(err)?)
+        } else {
+            Value::Int(parse_int(kind, value).map_err(err)?)
+        };
+        entries.push(Entry { kind, value, line });
+    }
+    Ok(Replay { entries, next: 0 })
+}
+
+fn from_env() -> State {
+    match std::env::var_os(REPLAY_ENV_VAR) {
+        Some(path) => match load(Path::new(&path)) {
+            Ok(replay) => State::On(replay),
+            Err(e) => panic!("cvlr replay: {}", e),
+        },
+        None => State::Off,
+    }
+}
+
+fn load(path: &Path) -> Result<Replay, ReplayError> {
+    let src = std::fs::read_to_string(path).map_err(|err| ReplayError::Io {
+        path: path.to_path_buf(),
+        err,
+    })?;
+    parse(&src)
+}
+
+fn with<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    REPLAY.with(|cell| {
+        let mut state = cell.borrow_mut();
+        if let State::Uninit = *state {
+            *state = from_env();
+        }
+        f(&mut state)
+    })
+}
+
+/// Takes the next entry, which must be of `kind`
+fn take(kind: ValueKind) -> Option<Value> {
+    with(|state| {
+        let State::On(replay) = state else {
+            return None;
+        };
+        let Some(entry) = replay.entries.get(replay.next) else {
+            panic!(
+                "cvlr replay: counterexample exhausted after {} values, \
+                 but a {} value was requested",
+                replay.entries.len(),
+                kind
+            );
+        };
+        if entry.kind != kind {
+            panic!(
+                "cvlr replay: type mismatch at value {} (line {}): \
+                 a {} value was requested, but the counterexample has {}",
+                replay.next + 1,
+                entry.line,
+                kind,
+                entry.kind
+            );
+        }
+        let value = match &entry.value {
+            Value::Int(v) => Value::Int(*v),
+            Value::Bytes(b) => Value::Bytes(b.clone()),
+        };
+        replay.next += 1;
+        Some(value)
+    })
+}
+
+pub(super) fn next_int(kind: ValueKind) -> Option<u128> {
+    match take(kind)? {
+        Value::Int(v) => Some(v),
+        Value::Bytes(_) => unreachable!(),
+    }
+}
+
+pub(super) fn fill_bytes(buf: &mut [u8]) -> bool {
+    let (line, pos) = with(|state| match state {
+        State::On(replay) => (
+            replay.entries.get(replay.next).map_or(0, |e| e.line),
+            replay.next + 1,
+        ),
+        _ => (0, 0),
+    });
+    match take(ValueKind::Bytes) {
+        Some(Value::Bytes(bytes)) => {
+            if bytes.len() != buf.len() {
+                panic!(
+                    "cvlr replay: length mismatch at value {} (line {}): \
+                     {} bytes were requested, but the counterexample has {}",
+                    pos,
+                    line,
+                    buf.len(),
+                    bytes.len()
+                );
+            }
+            buf.copy_from_slice(&bytes);
+            true
+        }
+        Some(Value::Int(_)) => unreachable!(),
+        None => false,
+    }
+}
+
+/// Replay nondet values from the counterexample file at `path`
+///
+/// Overrides `CVLR_REPLAY` for the current thread.
+pub fn replay_file(path: impl AsRef<Path>) -> Result<(), ReplayError> {
+    let replay = load(path.as_ref())?;
+    with(|state| *state = State::On(replay));
+    Ok(())
+}
+
+/// Replay nondet values from a counterexample given as a string
+pub fn replay_str(src: &str) -> Result<(), ReplayError> {
+    let replay = parse(src)?;
+    with(|state| *state = State::On(replay));
+    Ok(())
+}
+
+/// Stop replaying, so that values come from the seed or defaults again
+pub fn clear_replay() {
+    with(|state| *state = State::Off);
+}
+
+/// Number of values left to replay, or `None` if no replay is active
+pub fn replay_remaining() -> Option<usize> {
+    with(|state| match state {
+        State::On(replay) => Some(replay.entries.len() - replay.next),
+        _ => None,
+    })
+}
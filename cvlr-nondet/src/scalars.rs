@@ -26,28 +26,29 @@ mod rt_decls {
 mod rt_impls {
     macro_rules! impl_rt_fn {
         // 1st argument is ignored
-        ($name:ident, $c_name:ident, $ty:ident) => {
+        ($name:ident, $c_name:ident, $ty:ident, $kind:ident) => {
             #[no_mangle]
             pub extern "C" fn $c_name() -> $ty {
-                $ty::default()
+                crate::rt::next_for(crate::rt::ValueKind::$kind)
+                    .map_or($ty::default(), |v| v as $ty)
             }
         };
     }
-    impl_rt_fn!(cvlr_nondet_u8, CVT_nondet_u8, u8);
-    impl_rt_fn!(cvlr_nondet_u16, CVT_nondet_u16, u16);
-    impl_rt_fn!(cvlr_nondet_u32, CVT_nondet_u32, u32);
-    impl_rt_fn!(cvlr_nondet_u64, CVT_nondet_u64, u64);
-    impl_rt_fn!(cvlr_nondet_u128, CVT_nondet_u128, u128);
-    impl_rt_fn!(cvlr_nondet_usize, CVT_nondet_usize, usize);
+    impl_rt_fn!(cvlr_nondet_u8, CVT_nondet_u8, u8, U8);
+    impl_rt_fn!(cvlr_nondet_u16, CVT_nondet_u16, u16, U16);
+    impl_rt_fn!(cvlr_nondet_u32, CVT_nondet_u32, u32, U32);
+    impl_rt_fn!(cvlr_nondet_u64, CVT_nondet_u64, u64, U64);
+    impl_rt_fn!(cvlr_nondet_u128, CVT_nondet_u128, u128, U128);
+    impl_rt_fn!(cvlr_nondet_usize, CVT_nondet_usize, usize, Usize);
 
-    impl_rt_fn!(cvlr_nondet_i8, CVT_nondet_i8, i8);
-    impl_rt_fn!(cvlr_nondet_i16, CVT_nondet_i16, i16);
-    impl_rt_fn!(cvlr_nondet_i32, CVT_nondet_i32, i32);
-    impl_rt_fn!(cvlr_nondet_i64, CVT_nondet_i64, i64);
-    impl_rt_fn!(cvlr_nondet_i128, CVT_nondet_i128, i128);
+    impl_rt_fn!(cvlr_nondet_i8, CVT_nondet_i8, i8, I8);
+    impl_rt_fn!(cvlr_nondet_i16, CVT_nondet_i16, i16, I16);
+    impl_rt_fn!(cvlr_nondet_i32, CVT_nondet_i32, i32, I32);
+    impl_rt_fn!(cvlr_nondet_i64, CVT_nondet_i64, i64, I64);
+    impl_rt_fn!(cvlr_nondet_i128, CVT_nondet_i128, i128, I128);
 
-    impl_rt_fn!(cvlr_nondet_small_u128, CVT_nondet_small_u128, u128);
-    impl_rt_fn!(cvlr_nondet_small_i128, CVT_nondet_small_i128, i128);
+    impl_rt_fn!(cvlr_nondet_small_u128, CVT_nondet_small_u128, u128, U128);
+    impl_rt_fn!(cvlr_nondet_small_i128, CVT_nondet_small_i128, i128, I128);
 }
 
 use rt_decls::*;
@@ -91,3 +92,55 @@ crate::nondet_impl! { u128, cvlr_nondet_u128(), "Nondet for u128" }
 crate::nondet_impl! { i128, cvlr_nondet_i128(), "Nondet for i128" }
 
 crate::nondet_impl! { usize, cvlr_nondet_usize(), "Nondet for usize" }
+
+macro_rules! impl_nondet_nonzero {
+    ($nz:ty, $t:ty) => {
+        impl Nondet for $nz {
+            #[inline]
+            #[doc = concat!("Nondet for ", stringify!($nz))]
+            fn nondet() -> $nz {
+                let v = <$t>::nondet();
+                // -- rt values are not symbolic, pick a value that satisfies the assumption
+                #[cfg(feature = "rt")]
+                let v = if v == 0 { 1 } else { v };
+                cvlr_asserts::cvlr_assume!(v != 0);
+                <$nz>::new(v).unwrap()
+            }
+        }
+    };
+}
+
+impl_nondet_nonzero! { core::num::NonZeroU8, u8 }
+impl_nondet_nonzero! { core::num::NonZeroU16, u16 }
+impl_nondet_nonzero! { core::num::NonZeroU32, u32 }
+impl_nondet_nonzero! { core::num::NonZeroU64, u64 }
+impl_nondet_nonzero! { core::num::NonZeroU128, u128 }
+impl_nondet_nonzero! { core::num::NonZeroUsize, usize }
+impl_nondet_nonzero! { core::num::NonZeroI8, i8 }
+impl_nondet_nonzero! { core::num::NonZeroI16, i16 }
+impl_nondet_nonzero! { core::num::NonZeroI32, i32 }
+impl_nondet_nonzero! { core::num::NonZeroI64, i64 }
+impl_nondet_nonzero! { core::num::NonZeroI128, i128 }
+
+impl Nondet for char {
+    /// Nondet for char, restricted to valid unicode scalar values
+    #[inline]
+    fn nondet() -> char {
+        let v = cvlr_nondet_u32();
+        // -- rt values are not symbolic, map invalid values into the scalar
+        // -- ranges, skipping the surrogate gap
+        #[cfg(feature = "rt")]
+        let v = if v < 0xD800 || (0xE000..=0x10FFFF).contains(&v) {
+            v
+        } else {
+            let v = crate::range::rt_wrap_into(v as u128, 0, 0x10F7FF) as u32;
+            if v < 0xD800 {
+                v
+            } else {
+                v + 0x800
+            }
+        };
+        cvlr_asserts::cvlr_assume!(v < 0xD800 || (0xE000..=0x10FFFF).contains(&v));
+        char::from_u32(v).unwrap()
+    }
+}
@@ -0,0 +1,55 @@
+use cvlr::nondet::{nondet_bytes, nondet_string, nondet_vec};
+use cvlr::prelude::clog;
+
+#[test]
+fn test_nondet_vec() {
+    let v: Vec<u64> = nondet_vec(8);
+    assert!(v.len() <= 8);
+    clog("v", &v);
+
+    let v: Vec<(u8, bool)> = nondet_vec(0);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_nondet_bytes() {
+    let data = nondet_bytes(32);
+    assert!(data.len() <= 32);
+    clog("data", &data);
+}
+
+#[test]
+fn test_nondet_string() {
+    let name = nondet_string(16);
+    assert!(name.len() <= 16);
+    assert!(name.is_ascii());
+    clog("name", &name);
+}
+
+#[test]
+fn test_nondet_string_rt() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let name = nondet_string(16);
+            assert!(name.is_ascii());
+        },
+        100,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+    assert_eq!(report.discarded, 0);
+}
+
+#[test]
+fn test_nondet_char_rt() {
+    let report = cvlr::rt::run_rule(
+        || {
+            let c: char = cvlr::nondet::nondet();
+            clog("c", &(c as u32));
+        },
+        100,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+    assert_eq!(report.discarded, 0);
+}
@@ -0,0 +1,60 @@
+use core::num::{NonZeroI32, NonZeroU64, NonZeroU8};
+use cvlr::nondet::{nondet, Nondet};
+
+#[test]
+fn test_array() {
+    let arr: [u64; 4] = nondet();
+    assert_eq!(arr.len(), 4);
+    let _empty: [u8; 0] = nondet();
+    let _nested = <[[i16; 2]; 3]>::nondet();
+}
+
+#[test]
+fn test_tuples() {
+    let _pair: (u8, bool) = nondet();
+    let _single: (u64,) = nondet();
+    let _twelve: (
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        bool,
+    ) = nondet();
+}
+
+#[test]
+fn test_result() {
+    let r: Result<u64, i32> = nondet();
+    match r {
+        Ok(_) | Err(_) => {}
+    }
+}
+
+#[test]
+fn test_nonzero() {
+    let x: NonZeroU8 = nondet();
+    assert_ne!(x.get(), 0);
+    let x: NonZeroU64 = nondet();
+    assert_ne!(x.get(), 0);
+    let x = NonZeroI32::nondet();
+    assert_ne!(x.get(), 0);
+}
+
+#[test]
+fn test_char() {
+    let c: char = nondet();
+    assert!(char::from_u32(c as u32).is_some());
+}
+
+#[test]
+fn test_box() {
+    let b: Box<[u32; 2]> = nondet();
+    assert_eq!(b.len(), 2);
+}
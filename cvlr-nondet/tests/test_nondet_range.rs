@@ -0,0 +1,48 @@
+use cvlr::mathint::NativeInt;
+use cvlr::nondet::{nondet_in, nondet_in_range, NondetRange};
+
+#[test]
+fn test_unsigned_in_range() {
+    let x: u8 = nondet_in_range(10..=20);
+    assert!((10..=20).contains(&x));
+
+    let x: u64 = nondet_in(5..6);
+    assert_eq!(x, 5);
+
+    let x = u128::nondet_in(1000..);
+    assert!(x >= 1000);
+
+    let x: usize = nondet_in(..=7);
+    assert!(x <= 7);
+}
+
+#[test]
+fn test_signed_in_range() {
+    let x: i8 = nondet_in_range(-20..=-10);
+    assert!((-20..=-10).contains(&x));
+
+    let x: i64 = nondet_in(-5..5);
+    assert!((-5..5).contains(&x));
+
+    let x: i128 = nondet_in(i128::MIN..=i128::MIN);
+    assert_eq!(x, i128::MIN);
+}
+
+#[test]
+fn test_full_range() {
+    let x: u32 = nondet_in(..);
+    let _ = x;
+    let x: i16 = nondet_in_range(i16::MIN..=i16::MAX);
+    let _ = x;
+}
+
+#[test]
+fn test_nativeint_in_range() {
+    let lo: NativeInt = 100u64.into();
+    let hi: NativeInt = 200u64.into();
+    let x: NativeInt = nondet_in_range(lo..=hi);
+    assert!(x >= lo && x <= hi);
+
+    let x: NativeInt = nondet_in(lo..);
+    assert!(x >= lo);
+}
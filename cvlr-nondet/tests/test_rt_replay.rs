@@ -0,0 +1,105 @@
+use cvlr::mathint::NativeInt;
+use cvlr::nondet::{havoc::alloc_ref_havoced, nondet, nondet_bytes, rt, rt::ValueKind};
+
+const CEX: &str = "
+# counterexample for rule_foo
+u64 42
+i32 -7
+u128 0xffff_ffff_ffff_ffff_ffff
+nativeint 1000
+u64 1
+bytes 00ff10
+";
+
+#[test]
+fn test_replay_in_order() {
+    rt::replay_str(CEX).unwrap();
+    assert_eq!(rt::replay_remaining(), Some(6));
+    assert_eq!(nondet::<u64>(), 42);
+    assert_eq!(nondet::<i32>(), -7);
+    assert_eq!(nondet::<u128>(), 0xffff_ffff_ffff_ffff_ffff);
+    assert_eq!(nondet::<NativeInt>(), NativeInt::from(1000u64));
+    assert!(nondet::<bool>());
+    assert_eq!(*alloc_ref_havoced::<[u8; 3]>(), [0x00, 0xff, 0x10]);
+    assert_eq!(rt::replay_remaining(), Some(0));
+    rt::clear_replay();
+    assert_eq!(rt::replay_remaining(), None);
+    assert_eq!(nondet::<u64>(), 0);
+}
+
+#[test]
+fn test_replay_takes_precedence_over_seed() {
+    rt::set_seed(7);
+    rt::replay_str("u8 5\nusize 3\nbytes 010203").unwrap();
+    assert_eq!(nondet::<u8>(), 5);
+    assert_eq!(nondet_bytes(8), vec![1, 2, 3]);
+    rt::clear_replay();
+    rt::clear_seed();
+}
+
+#[test]
+fn test_replay_in_range() {
+    rt::replay_str("u64 11\ni8 -3").unwrap();
+    assert_eq!(cvlr::nondet::nondet_in::<u64, _>(10..=12), 11);
+    assert_eq!(cvlr::nondet::nondet_in::<i8, _>(-5..0), -3);
+    rt::clear_replay();
+}
+
+#[test]
+fn test_replay_file() {
+    let path = std::env::temp_dir().join(format!("cvlr_replay_{}.cex", std::process::id()));
+    std::fs::write(&path, "i8 -128\ni64 0x10\n").unwrap();
+    rt::replay_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(nondet::<i8>(), i8::MIN);
+    assert_eq!(nondet::<i64>(), 16);
+    rt::clear_replay();
+
+    let err = rt::replay_file(&path).unwrap_err();
+    assert!(err.to_string().starts_with("cannot read counterexample"));
+}
+
+#[test]
+fn test_replay_parse_errors() {
+    let err = rt::replay_str("u64 1\nu8 256").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "malformed counterexample at line 2: `256` is out of range for u8"
+    );
+    let err = rt::replay_str("f32 1.0").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "malformed counterexample at line 1: unknown value kind `f32`"
+    );
+    let err = rt::replay_str("bytes 123").unwrap_err();
+    assert!(err.to_string().contains("odd number of hex digits"));
+    let err = rt::replay_str("u32").unwrap_err();
+    assert!(err.to_string().contains("expected `<kind> <value>`"));
+}
+
+#[test]
+#[should_panic(
+    expected = "cvlr replay: counterexample exhausted after 1 values, but a u64 value was requested"
+)]
+fn test_replay_exhausted() {
+    rt::replay_str("u64 1").unwrap();
+    rt::next_for(ValueKind::U64);
+    rt::next_for(ValueKind::U64);
+}
+
+#[test]
+#[should_panic(
+    expected = "cvlr replay: type mismatch at value 2 (line 3): a u32 value was requested, but the counterexample has i32"
+)]
+fn test_replay_type_mismatch() {
+    rt::replay_str("u64 1\n\ni32 2").unwrap();
+    rt::next_for(ValueKind::U64);
+    rt::next_for(ValueKind::U32);
+}
+
+#[test]
+#[should_panic(expected = "4 bytes were requested, but the counterexample has 2")]
+fn test_replay_bytes_length_mismatch() {
+    rt::replay_str("bytes abcd").unwrap();
+    rt::fill_bytes(&mut [0u8; 4]);
+}
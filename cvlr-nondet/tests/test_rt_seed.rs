@@ -0,0 +1,79 @@
+use cvlr::mathint::NativeInt;
+use cvlr::nondet::{havoc::alloc_ref_havoced, nondet, rt};
+
+fn draw() -> (u8, i32, u64, u128, i128, bool, NativeInt) {
+    (
+        nondet(),
+        nondet(),
+        nondet(),
+        nondet(),
+        nondet(),
+        nondet(),
+        nondet(),
+    )
+}
+
+#[test]
+fn test_unseeded_is_default() {
+    rt::clear_seed();
+    assert_eq!(rt::seed(), None);
+    assert_eq!(rt::next_u64(), None);
+    let (a, b, c, d, e, f, g) = draw();
+    assert_eq!((a, b, c, d, e, f), (0, 0, 0, 0, 0, false));
+    assert_eq!(g, NativeInt::from(0u64));
+    assert_eq!(*alloc_ref_havoced::<[u8; 16]>(), [0u8; 16]);
+}
+
+#[test]
+fn test_same_seed_same_values() {
+    rt::set_seed(42);
+    assert_eq!(rt::seed(), Some(42));
+    let first = draw();
+    let first_mem = *alloc_ref_havoced::<[u8; 13]>();
+
+    rt::set_seed(42);
+    let second = draw();
+    let second_mem = *alloc_ref_havoced::<[u8; 13]>();
+
+    assert_eq!(first.2, second.2);
+    assert_eq!(first.3, second.3);
+    assert_eq!(first.6, second.6);
+    assert_eq!(first_mem, second_mem);
+    assert_ne!(first_mem, [0u8; 13]);
+}
+
+#[test]
+fn test_different_seeds_differ() {
+    rt::set_seed(1);
+    let a: u64 = nondet();
+    rt::set_seed(2);
+    let b: u64 = nondet();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_seed_from_env() {
+    let values = |seed: &'static str| {
+        std::thread::spawn(move || {
+            std::env::set_var(rt::SEED_ENV_VAR, seed);
+            let seed = rt::seed();
+            let v: u64 = nondet();
+            std::env::remove_var(rt::SEED_ENV_VAR);
+            (seed, v)
+        })
+        .join()
+        .unwrap()
+    };
+    let (seed, v) = values("0x2a");
+    assert_eq!(seed, Some(42));
+    rt::set_seed(42);
+    assert_eq!(v, nondet::<u64>());
+    assert_eq!(values("42"), (Some(42), v));
+}
+
+#[test]
+fn test_parse_seed() {
+    assert_eq!(rt::parse_seed("17"), Some(17));
+    assert_eq!(rt::parse_seed(" 0xff "), Some(255));
+    assert_eq!(rt::parse_seed("seed"), None);
+}
@@ -1,5 +1,7 @@
 //! Combinators for composing boolean expressions.
 
+use cvlr_asserts::{cvlr_assert, cvlr_assume};
+
 use crate::formula::CvlrFormula;
 
 /// A boolean expression representing the logical AND of two expressions.
@@ -146,3 +148,213 @@ where
 {
     CvlrImplies(a, b)
 }
+
+/// A boolean expression representing the logical OR of two expressions.
+///
+/// This expression evaluates to `true` when at least one sub-expression evaluates
+/// to `true`. When asserting or assuming, the second sub-expression is only
+/// processed if the first one evaluates to `false`, so that a failing
+/// disjunction reports the conjuncts of `B`.
+#[derive(Copy, Clone)]
+pub struct CvlrOr<A, B>(A, B);
+
+impl<A, B> CvlrFormula for CvlrOr<A, B>
+where
+    A: CvlrFormula,
+    B: CvlrFormula<Context = A::Context>,
+{
+    type Context = A::Context;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        self.0.eval(ctx) || self.1.eval(ctx)
+    }
+
+    fn assert(&self, ctx: &Self::Context) {
+        if !self.0.eval(ctx) {
+            self.1.assert(ctx);
+        }
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        if !self.0.eval(ctx) {
+            self.1.assume(ctx);
+        }
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        self.0.eval_with_states(ctx0, ctx1) || self.1.eval_with_states(ctx0, ctx1)
+    }
+
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if !self.0.eval_with_states(ctx0, ctx1) {
+            self.1.assert_with_states(ctx0, ctx1)
+        }
+    }
+
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if !self.0.eval_with_states(ctx0, ctx1) {
+            self.1.assume_with_states(ctx0, ctx1)
+        }
+    }
+}
+
+/// Combines two boolean expressions with logical OR.
+///
+/// Returns a new expression that evaluates to `true` when at least one of the
+/// input expressions evaluates to `true`.
+///
+/// # Arguments
+///
+/// * `a` - The first boolean expression
+/// * `b` - The second boolean expression
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_not, cvlr_or, cvlr_true, CvlrFormula};
+///
+/// // false ∨ true evaluates to true
+/// let expr = cvlr_or(cvlr_not(cvlr_true::<()>()), cvlr_true::<()>());
+/// assert!(expr.eval(&()));
+/// ```
+pub fn cvlr_or<A, B>(a: A, b: B) -> CvlrOr<A, B>
+where
+    A: CvlrFormula,
+    B: CvlrFormula<Context = A::Context>,
+{
+    CvlrOr(a, b)
+}
+
+/// A boolean expression representing the logical negation of an expression.
+///
+/// This expression evaluates to `true` exactly when the sub-expression evaluates
+/// to `false`. Asserting or assuming the negation checks or assumes that the
+/// sub-expression evaluates to `false`; the sub-expression's own `assert` and
+/// `assume` are never used, since they would check or assume the opposite.
+#[derive(Copy, Clone)]
+pub struct CvlrNot<A>(A);
+
+impl<A> CvlrFormula for CvlrNot<A>
+where
+    A: CvlrFormula,
+{
+    type Context = A::Context;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        !self.0.eval(ctx)
+    }
+
+    fn assert(&self, ctx: &Self::Context) {
+        cvlr_assert!(!self.0.eval(ctx));
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        cvlr_assume!(!self.0.eval(ctx));
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        !self.0.eval_with_states(ctx0, ctx1)
+    }
+
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        cvlr_assert!(!self.0.eval_with_states(ctx0, ctx1));
+    }
+
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        cvlr_assume!(!self.0.eval_with_states(ctx0, ctx1));
+    }
+}
+
+/// Creates a boolean expression representing the negation of `a`.
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_not, cvlr_true, CvlrFormula};
+///
+/// let expr = cvlr_not(cvlr_true::<()>());
+/// assert!(!expr.eval(&()));
+/// ```
+pub fn cvlr_not<A>(a: A) -> CvlrNot<A>
+where
+    A: CvlrFormula,
+{
+    CvlrNot(a)
+}
+
+/// A boolean expression representing logical equivalence (A ↔ B).
+///
+/// This expression evaluates to `true` when both sub-expressions evaluate to the
+/// same value. When asserting or assuming, `B` is asserted or assumed if `A`
+/// evaluates to `true`, and its negation is asserted or assumed otherwise.
+#[derive(Copy, Clone)]
+pub struct CvlrIff<A, B>(A, B);
+
+impl<A, B> CvlrFormula for CvlrIff<A, B>
+where
+    A: CvlrFormula,
+    B: CvlrFormula<Context = A::Context>,
+{
+    type Context = A::Context;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        self.0.eval(ctx) == self.1.eval(ctx)
+    }
+
+    fn assert(&self, ctx: &Self::Context) {
+        if self.0.eval(ctx) {
+            self.1.assert(ctx);
+        } else {
+            cvlr_assert!(!self.1.eval(ctx));
+        }
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        if self.0.eval(ctx) {
+            self.1.assume(ctx);
+        } else {
+            cvlr_assume!(!self.1.eval(ctx));
+        }
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        self.0.eval_with_states(ctx0, ctx1) == self.1.eval_with_states(ctx0, ctx1)
+    }
+
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if self.0.eval_with_states(ctx0, ctx1) {
+            self.1.assert_with_states(ctx0, ctx1);
+        } else {
+            cvlr_assert!(!self.1.eval_with_states(ctx0, ctx1));
+        }
+    }
+
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if self.0.eval_with_states(ctx0, ctx1) {
+            self.1.assume_with_states(ctx0, ctx1);
+        } else {
+            cvlr_assume!(!self.1.eval_with_states(ctx0, ctx1));
+        }
+    }
+}
+
+/// Creates a boolean expression representing logical equivalence (A ↔ B).
+///
+/// Returns a new expression that evaluates to `true` when `a` and `b` evaluate
+/// to the same value.
+///
+/// # Arguments
+///
+/// * `a` - The left-hand side of the equivalence
+/// * `b` - The right-hand side of the equivalence
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_iff, cvlr_not, cvlr_true, CvlrFormula};
+///
+/// // false ↔ false evaluates to true
+/// let expr = cvlr_iff(cvlr_not(cvlr_true::<()>()), cvlr_not(cvlr_true::<()>()));
+/// assert!(expr.eval(&()));
+/// ```
+pub fn cvlr_iff<A, B>(a: A, b: B) -> CvlrIff<A, B>
+where
+    A: CvlrFormula,
+    B: CvlrFormula<Context = A::Context>,
+{
+    CvlrIff(a, b)
+}
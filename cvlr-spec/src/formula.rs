@@ -142,3 +142,181 @@ impl<Ctx> CvlrFormula for CvlrTrue<Ctx> {
 pub fn cvlr_true<Ctx>() -> impl CvlrFormula<Context = Ctx> {
     CvlrTrue(core::marker::PhantomData)
 }
+
+/// Logs the tag of a closure formula in a `kind` scope, as predicates log their conjuncts
+fn log_fn_tag(kind: &str, tag: &str) {
+    cvlr_log::log_scope_start(kind);
+    cvlr_log::cvlr_log("_", &tag);
+    cvlr_log::log_scope_end(kind);
+}
+
+/// A boolean expression defined by a closure or function over one state.
+///
+/// Created by [`cvlr_fn`] or [`cvlr_fn!`](crate::cvlr_fn!). When asserted or
+/// assumed, the tag of the formula is logged to the calltrace and attached as
+/// the description of the check.
+#[derive(Copy, Clone)]
+pub struct CvlrFn<Ctx, F> {
+    f: F,
+    tag: &'static str,
+    _ctx: core::marker::PhantomData<fn(&Ctx)>,
+}
+
+impl<Ctx, F> CvlrFn<Ctx, F> {
+    /// Replaces the tag used in the calltrace
+    pub fn with_tag(self, tag: &'static str) -> Self {
+        Self { tag, ..self }
+    }
+
+    /// The tag used in the calltrace
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+}
+
+impl<Ctx, F> CvlrFormula for CvlrFn<Ctx, F>
+where
+    F: Fn(&Ctx) -> bool,
+{
+    type Context = Ctx;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        (self.f)(ctx)
+    }
+    fn assert(&self, ctx: &Self::Context) {
+        let c = self.eval(ctx);
+        log_fn_tag("assert", self.tag);
+        cvlr_asserts::log::add_desc(self.tag);
+        cvlr_asserts::cvlr_assert_checked_with_desc(c, self.tag);
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        let c = self.eval(ctx);
+        log_fn_tag("assume", self.tag);
+        cvlr_asserts::log::add_desc(self.tag);
+        cvlr_asserts::cvlr_assume_checked_with_desc(c, self.tag);
+    }
+}
+
+/// Turns a closure or function over one state into a [`CvlrFormula`].
+///
+/// The formula is tagged with the type name of `f`. Use [`cvlr_fn!`](crate::cvlr_fn!)
+/// to tag it with its source text instead.
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_fn, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// fn is_even(c: &Counter) -> bool {
+///     c.value % 2 == 0
+/// }
+///
+/// let non_negative = cvlr_fn(|c: &Counter| c.value >= 0);
+/// assert!(non_negative.eval(&Counter { value: 2 }));
+/// assert!(!cvlr_fn(is_even).eval(&Counter { value: 3 }));
+/// ```
+pub fn cvlr_fn<Ctx, F>(f: F) -> CvlrFn<Ctx, F>
+where
+    F: Fn(&Ctx) -> bool,
+{
+    CvlrFn {
+        f,
+        tag: core::any::type_name::<F>(),
+        _ctx: core::marker::PhantomData,
+    }
+}
+
+/// A boolean expression defined by a closure or function over two states.
+///
+/// Created by [`cvlr_fn2`] or [`cvlr_fn2!`](crate::cvlr_fn2!). The closure
+/// receives the post-state first and the pre-state second, as in
+/// [`eval_with_states`](CvlrFormula::eval_with_states). Like two-state
+/// predicates, it must only be used through the `_with_states` methods.
+#[derive(Copy, Clone)]
+pub struct CvlrFn2<Ctx, F> {
+    f: F,
+    tag: &'static str,
+    _ctx: core::marker::PhantomData<fn(&Ctx)>,
+}
+
+impl<Ctx, F> CvlrFn2<Ctx, F> {
+    /// Replaces the tag used in the calltrace
+    pub fn with_tag(self, tag: &'static str) -> Self {
+        Self { tag, ..self }
+    }
+
+    /// The tag used in the calltrace
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+}
+
+impl<Ctx, F> CvlrFormula for CvlrFn2<Ctx, F>
+where
+    F: Fn(&Ctx, &Ctx) -> bool,
+{
+    type Context = Ctx;
+    fn eval(&self, _ctx: &Self::Context) -> bool {
+        cvlr_assert!(false);
+        panic!("eval should never be called for a two-state formula; use eval_with_states instead");
+    }
+    fn assert(&self, _ctx: &Self::Context) {
+        cvlr_assert!(false);
+        panic!(
+            "assert should never be called for a two-state formula; use assert_with_states instead"
+        );
+    }
+    fn assume(&self, _ctx: &Self::Context) {
+        cvlr_assert!(false);
+        panic!(
+            "assume should never be called for a two-state formula; use assume_with_states instead"
+        );
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        (self.f)(ctx0, ctx1)
+    }
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        let c = self.eval_with_states(ctx0, ctx1);
+        log_fn_tag("assert", self.tag);
+        cvlr_asserts::log::add_desc(self.tag);
+        cvlr_asserts::cvlr_assert_checked_with_desc(c, self.tag);
+    }
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        let c = self.eval_with_states(ctx0, ctx1);
+        log_fn_tag("assume", self.tag);
+        cvlr_asserts::log::add_desc(self.tag);
+        cvlr_asserts::cvlr_assume_checked_with_desc(c, self.tag);
+    }
+}
+
+/// Turns a closure or function over a post-state and a pre-state into a [`CvlrFormula`].
+///
+/// The formula is tagged with the type name of `f`. Use [`cvlr_fn2!`](crate::cvlr_fn2!)
+/// to tag it with its source text instead.
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_fn2, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let increases = cvlr_fn2(|c: &Counter, o: &Counter| c.value > o.value);
+/// assert!(increases.eval_with_states(&Counter { value: 2 }, &Counter { value: 1 }));
+/// ```
+pub fn cvlr_fn2<Ctx, F>(f: F) -> CvlrFn2<Ctx, F>
+where
+    F: Fn(&Ctx, &Ctx) -> bool,
+{
+    CvlrFn2 {
+        f,
+        tag: core::any::type_name::<F>(),
+        _ctx: core::marker::PhantomData,
+    }
+}
@@ -0,0 +1,150 @@
+//! Frame conditions: fields that do not change between two states.
+
+use cvlr_asserts::cvlr_assert;
+
+use crate::formula::CvlrFormula;
+
+/// A context whose fields can be compared between two states.
+///
+/// Implemented with `#[derive(CvlrFrame)]`, which compares every field with
+/// `==` and logs each compared pair, so that the calltrace of a failed frame
+/// condition shows the field that changed.
+///
+/// Fields that may change are given as a mask, in which bit `i` stands for
+/// `FIELDS[i]`, so that a frame with a constant mask compiles to constant
+/// conditions.
+pub trait CvlrFrame {
+    /// Names of the fields, as accepted by [`Frame::except`], at most 64
+    const FIELDS: &'static [&'static str];
+
+    /// Whether every field not in the `except` mask is equal in `self` and `old`
+    fn eval_frame(&self, old: &Self, except: u64) -> bool;
+
+    /// Asserts that every field not in the `except` mask is unchanged
+    fn assert_frame(&self, old: &Self, except: u64);
+
+    /// Assumes that every field not in the `except` mask is unchanged
+    fn assume_frame(&self, old: &Self, except: u64);
+}
+
+/// Whether `a` and `b` are equal, in a `const` context
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A two-state boolean expression stating that a context did not change, except
+/// for the given fields.
+///
+/// The post-state is the first context and the pre-state the second one, as in
+/// [`eval_with_states`](CvlrFormula::eval_with_states). Like two-state
+/// predicates, it must only be used through the `_with_states` methods.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr::prelude::*;
+///
+/// #[derive(CvlrFrame)]
+/// struct Account {
+///     owner: u64,
+///     balance: u64,
+/// }
+///
+/// // -- nothing but the balance changed
+/// let frame = cvlr_frame!(Account, except: [balance]);
+/// frame.assert_with_states(&post, &pre);
+/// ```
+///
+/// [`cvlr_frame!`](crate::cvlr_frame) checks the field names at compile time.
+/// [`Frame::except`] is a `const fn` that does the same when it is evaluated
+/// in a `const`, and panics on an unknown name otherwise.
+pub struct Frame<Ctx> {
+    except: u64,
+    _ctx: core::marker::PhantomData<fn(&Ctx)>,
+}
+
+impl<Ctx> Clone for Frame<Ctx> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Ctx> Copy for Frame<Ctx> {}
+
+impl<Ctx: CvlrFrame> Frame<Ctx> {
+    /// No field changes
+    pub const fn unchanged() -> Self {
+        Self::except(&[])
+    }
+
+    /// No field other than those in `fields` changes
+    ///
+    /// Panics if a name in `fields` is not a field of `Ctx`, which is a compile
+    /// error when the frame is a `const`.
+    pub const fn except(fields: &[&str]) -> Self {
+        let mut except = 0u64;
+        let mut i = 0;
+        while i < fields.len() {
+            let mut j = 0;
+            while j < Ctx::FIELDS.len() && !str_eq(fields[i], Ctx::FIELDS[j]) {
+                j += 1;
+            }
+            if j == Ctx::FIELDS.len() {
+                panic!("Frame::except: unknown field");
+            }
+            except |= 1 << j;
+            i += 1;
+        }
+        Self {
+            except,
+            _ctx: core::marker::PhantomData,
+        }
+    }
+
+    /// Fields that are allowed to change
+    pub fn changed(&self) -> impl Iterator<Item = &'static str> {
+        let except = self.except;
+        Ctx::FIELDS
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| except & (1 << i) != 0)
+            .map(|(_, f)| *f)
+    }
+}
+
+impl<Ctx: CvlrFrame> CvlrFormula for Frame<Ctx> {
+    type Context = Ctx;
+    fn eval(&self, _ctx: &Self::Context) -> bool {
+        cvlr_assert!(false);
+        panic!("eval should never be called for a frame; use eval_with_states instead");
+    }
+    fn assert(&self, _ctx: &Self::Context) {
+        cvlr_assert!(false);
+        panic!("assert should never be called for a frame; use assert_with_states instead");
+    }
+    fn assume(&self, _ctx: &Self::Context) {
+        cvlr_assert!(false);
+        panic!("assume should never be called for a frame; use assume_with_states instead");
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        ctx0.eval_frame(ctx1, self.except)
+    }
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        ctx0.assert_frame(ctx1, self.except);
+    }
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        ctx0.assume_frame(ctx1, self.except);
+    }
+}
@@ -17,9 +17,22 @@
 //!
 //! Boolean expressions can be composed using:
 //! - [`cvlr_and`] - Logical AND
-//! - [`cvlr_impl`] - Logical implication (A → B)
+//! - [`cvlr_or`] - Logical OR
+//! - [`cvlr_not`] - Logical negation
+//! - [`cvlr_implies`] - Logical implication (A → B)
+//! - [`cvlr_iff`] - Logical equivalence (A ↔ B)
 //! - [`cvlr_true`] - Constant true expression
 //!
+//! Bounded quantifiers over index ranges are written with [`cvlr_forall`] and
+//! [`cvlr_exists`], which unroll over the range, or with [`cvlr_forall_nondet`]
+//! and [`cvlr_exists_nondet`], which pick a nondet index instead.
+//!
+//! Frame conditions, stating that nothing but some fields changed between two
+//! states, are written with [`Frame`] for contexts that derive [`CvlrFrame`].
+//!
+//! Closures and functions returning `bool` are turned into expressions with
+//! [`cvlr_fn`] (one state) and [`cvlr_fn2`] (post-state and pre-state).
+//!
 //! ## State Pairs
 //!
 //! Postconditions use [`eval_with_states`](CvlrFormula::eval_with_states) to evaluate
@@ -57,7 +70,9 @@
 
 mod combinators;
 mod formula;
+mod frame;
 mod macros;
+mod quantifiers;
 pub mod spec;
 
 #[doc(hidden)]
@@ -67,6 +82,13 @@ pub mod __macro_support {
 }
 
 // Re-export core types and traits
-pub use combinators::{cvlr_and, cvlr_implies, CvlrAnd, CvlrImplies};
-pub use formula::{cvlr_true, CvlrFormula, CvlrPredicate};
+pub use combinators::{
+    cvlr_and, cvlr_iff, cvlr_implies, cvlr_not, cvlr_or, CvlrAnd, CvlrIff, CvlrImplies, CvlrNot,
+    CvlrOr,
+};
+pub use formula::{cvlr_fn, cvlr_fn2, cvlr_true, CvlrFn, CvlrFn2, CvlrFormula, CvlrPredicate};
+pub use frame::{CvlrFrame, Frame};
+pub use quantifiers::{
+    cvlr_exists, cvlr_exists_nondet, cvlr_forall, cvlr_forall_nondet, CvlrExists, CvlrForall,
+};
 pub use spec::{cvlr_invar_spec, cvlr_spec, CvlrInvarSpec, CvlrPropImpl, CvlrSpec};
@@ -939,6 +939,60 @@ macro_rules! cvlr_and {
     };
 }
 
+/// Creates a boolean expression representing the logical OR of two or more expressions.
+///
+/// This macro is the disjunctive counterpart of [`cvlr_and!`]: it accepts between two and
+/// six expressions with the same context type and nests them to the right using
+/// [`cvlr_or`](crate::cvlr_or), i.e., `cvlr_or!(a, b, c)` is `cvlr_or(a, cvlr_or(b, c))`.
+///
+/// When asserted or assumed, the disjuncts are evaluated in order and the last one is
+/// asserted or assumed only if all the preceding ones evaluate to `false`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr_spec::{cvlr_or, cvlr_predicate, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let expr = cvlr_or!(
+///     cvlr_predicate! { | c : Counter | -> { c.value < 0 } },
+///     cvlr_predicate! { | c : Counter | -> { c.value > 100 } }
+/// );
+/// assert!(expr.eval(&Counter { value: 101 }));
+/// assert!(!expr.eval(&Counter { value: 50 }));
+/// ```
+#[macro_export]
+macro_rules! cvlr_or {
+    ($a:expr, $b:expr) => {
+        $crate::cvlr_or($a, $b)
+    };
+
+    ($a:expr, $b:expr, $c:expr) => {
+        $crate::cvlr_or($a, $crate::cvlr_or($b, $c))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr) => {
+        $crate::cvlr_or($a, $crate::cvlr_or($b, $crate::cvlr_or($c, $d)))
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr) => {
+        $crate::cvlr_or(
+            $a,
+            $crate::cvlr_or($b, $crate::cvlr_or($c, $crate::cvlr_or($d, $e))),
+        )
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr) => {
+        $crate::cvlr_or(
+            $a,
+            $crate::cvlr_or(
+                $b,
+                $crate::cvlr_or($c, $crate::cvlr_or($d, $crate::cvlr_or($e, $f))),
+            ),
+        )
+    };
+}
+
 /// Creates a boolean expression representing the logical AND of two or more predicate functions.
 ///
 /// This macro is similar to [`cvlr_and!`], but it automatically converts predicate function names
@@ -1167,3 +1221,145 @@ macro_rules! cvlr_implies {
         $crate::cvlr_implies($a, $b)
     };
 }
+
+/// Creates a boolean expression representing the negation of an expression.
+///
+/// `cvlr_not!(a)` is [`cvlr_not(a)`](crate::cvlr_not). Asserting (assuming) the result
+/// asserts (assumes) that `a` evaluates to `false`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr_spec::{cvlr_not, cvlr_predicate, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let expr = cvlr_not!(cvlr_predicate! { | c : Counter | -> { c.value > 0 } });
+/// assert!(expr.eval(&Counter { value: -1 }));
+/// ```
+#[macro_export]
+macro_rules! cvlr_not {
+    ($a:expr) => {
+        $crate::cvlr_not($a)
+    };
+}
+
+/// Creates a boolean expression representing logical equivalence (A ↔ B).
+///
+/// `cvlr_iff!(a, b)` is [`cvlr_iff(a, b)`](crate::cvlr_iff).
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr_spec::{cvlr_iff, cvlr_predicate, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let expr = cvlr_iff!(
+///     cvlr_predicate! { | c : Counter | -> { c.value > 0 } },
+///     cvlr_predicate! { | c : Counter | -> { c.value >= 1 } }
+/// );
+/// assert!(expr.eval(&Counter { value: 0 }));
+/// ```
+#[macro_export]
+macro_rules! cvlr_iff {
+    ($a:expr, $b:expr) => {
+        $crate::cvlr_iff($a, $b)
+    };
+}
+
+/// Turns a closure or function over one state into a [`CvlrFormula`](crate::CvlrFormula)
+/// tagged with its source text.
+///
+/// `cvlr_fn!(f)` is [`cvlr_fn(f)`](crate::cvlr_fn) with the tag `stringify!(f)`, so that
+/// the calltrace of a failing assert shows the condition as written.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr_spec::{cvlr_fn, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let non_negative = cvlr_fn!(|c: &Counter| c.value >= 0);
+/// assert_eq!(non_negative.tag(), "|c: &Counter| c.value >= 0");
+/// ```
+#[macro_export]
+macro_rules! cvlr_fn {
+    ($f:expr) => {
+        $crate::cvlr_fn($f).with_tag(stringify!($f))
+    };
+}
+
+/// Turns a closure or function over a post-state and a pre-state into a
+/// [`CvlrFormula`](crate::CvlrFormula) tagged with its source text.
+///
+/// `cvlr_fn2!(f)` is [`cvlr_fn2(f)`](crate::cvlr_fn2) with the tag `stringify!(f)`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr_spec::{cvlr_fn2, CvlrFormula};
+///
+/// struct Counter {
+///     value: i32,
+/// }
+///
+/// let increases = cvlr_fn2!(|c: &Counter, o: &Counter| c.value > o.value);
+/// ```
+#[macro_export]
+macro_rules! cvlr_fn2 {
+    ($f:expr) => {
+        $crate::cvlr_fn2($f).with_tag(stringify!($f))
+    };
+}
+
+/// Creates a [`Frame`](crate::Frame) over a context type, checking the names
+/// of the fields that may change at compile time.
+///
+/// The frame is a constant, so every field comparison is a constant condition.
+/// The context type cannot depend on generic parameters; use
+/// [`Frame::except`](crate::Frame::except) for those.
+///
+/// # Syntax
+///
+/// ```ignore
+/// cvlr_frame!(<context_type>)
+/// cvlr_frame!(<context_type>, except: [<field>, ...])
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use cvlr::prelude::*;
+///
+/// #[derive(CvlrFrame)]
+/// struct Account {
+///     owner: u64,
+///     balance: u64,
+/// }
+///
+/// // -- nothing but the balance changed
+/// cvlr_frame!(Account, except: [balance]).assert_with_states(&post, &pre);
+/// ```
+#[macro_export]
+macro_rules! cvlr_frame {
+    ($ctx:ty) => {
+        $crate::cvlr_frame!($ctx, except: [])
+    };
+    ($ctx:ty, except: [ $( $field:tt ),* $(,)? ]) => {{
+        // -- reports unknown fields at their name
+        let _ = |__cvlr_ctx: &$ctx| {
+            $( let _ = &__cvlr_ctx.$field; )*
+        };
+        const __CVLR_FRAME: $crate::Frame<$ctx> =
+            $crate::Frame::except(&[ $( ::core::stringify!($field) ),* ]);
+        __CVLR_FRAME
+    }};
+}
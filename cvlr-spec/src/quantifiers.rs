@@ -0,0 +1,270 @@
+//! Bounded quantifiers over index ranges.
+
+use core::ops::Range;
+
+use cvlr_asserts::{cvlr_assert, cvlr_assume};
+use cvlr_nondet::nondet_in;
+
+use crate::formula::CvlrFormula;
+
+/// A boolean expression representing `∀ i ∈ range. f(i)`.
+///
+/// By default, the quantifier is unrolled: every index in the range is
+/// evaluated, asserted, or assumed in turn, so the bounds of the range should
+/// be constants. A quantifier created by [`cvlr_forall_nondet`] asserts `f(i)`
+/// for a single nondet index `i` in the range instead, which covers every
+/// index symbolically without unrolling.
+#[derive(Clone)]
+pub struct CvlrForall<F> {
+    range: Range<usize>,
+    f: F,
+    nondet: bool,
+}
+
+impl<F, G> CvlrFormula for CvlrForall<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    type Context = G::Context;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        self.range.clone().all(|i| (self.f)(i).eval(ctx))
+    }
+
+    fn assert(&self, ctx: &Self::Context) {
+        if self.nondet {
+            if !self.range.is_empty() {
+                (self.f)(nondet_in(self.range.clone())).assert(ctx);
+            }
+        } else {
+            for i in self.range.clone() {
+                (self.f)(i).assert(ctx);
+            }
+        }
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        for i in self.range.clone() {
+            (self.f)(i).assume(ctx);
+        }
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        self.range
+            .clone()
+            .all(|i| (self.f)(i).eval_with_states(ctx0, ctx1))
+    }
+
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if self.nondet {
+            if !self.range.is_empty() {
+                (self.f)(nondet_in(self.range.clone())).assert_with_states(ctx0, ctx1);
+            }
+        } else {
+            for i in self.range.clone() {
+                (self.f)(i).assert_with_states(ctx0, ctx1);
+            }
+        }
+    }
+
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        for i in self.range.clone() {
+            (self.f)(i).assume_with_states(ctx0, ctx1);
+        }
+    }
+}
+
+/// Creates a universally quantified expression over the indices in `range`.
+///
+/// `f` maps an index to the formula that must hold for it. The quantifier is
+/// unrolled over `range`, which should have constant bounds.
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_fn, cvlr_forall, CvlrFormula};
+///
+/// struct Vault {
+///     balances: [u64; 4],
+///     cap: u64,
+/// }
+///
+/// let bounded = cvlr_forall(0..4, |i| cvlr_fn(move |v: &Vault| v.balances[i] <= v.cap));
+/// let vault = Vault { balances: [1, 2, 3, 4], cap: 4 };
+/// assert!(bounded.eval(&vault));
+/// ```
+pub fn cvlr_forall<F, G>(range: Range<usize>, f: F) -> CvlrForall<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    CvlrForall {
+        range,
+        f,
+        nondet: false,
+    }
+}
+
+/// Creates a universally quantified expression that is asserted for a nondet index.
+///
+/// Asserting the expression asserts `f(i)` for a single nondet `i` in `range`,
+/// so `range` may have symbolic bounds. Assuming the expression still assumes
+/// `f(i)` for every index in `range`.
+pub fn cvlr_forall_nondet<F, G>(range: Range<usize>, f: F) -> CvlrForall<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    CvlrForall {
+        range,
+        f,
+        nondet: true,
+    }
+}
+
+/// A boolean expression representing `∃ i ∈ range. f(i)`.
+///
+/// By default, the quantifier is unrolled. When asserting or assuming, the
+/// indices are evaluated in order, and the formula of the last index is
+/// asserted or assumed only if no index satisfies `f`, like a chain of
+/// [`cvlr_or`](crate::cvlr_or). An empty range is asserted or assumed as
+/// `false`. A quantifier created by [`cvlr_exists_nondet`] assumes `f(i)` for
+/// a single nondet index `i` in the range instead.
+#[derive(Clone)]
+pub struct CvlrExists<F> {
+    range: Range<usize>,
+    f: F,
+    nondet: bool,
+}
+
+impl<F, G> CvlrFormula for CvlrExists<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    type Context = G::Context;
+    fn eval(&self, ctx: &Self::Context) -> bool {
+        self.range.clone().any(|i| (self.f)(i).eval(ctx))
+    }
+
+    fn assert(&self, ctx: &Self::Context) {
+        let mut last = None;
+        for i in self.range.clone() {
+            let g = (self.f)(i);
+            if g.eval(ctx) {
+                return;
+            }
+            last = Some(g);
+        }
+        match last {
+            Some(g) => g.assert(ctx),
+            None => cvlr_assert!(false),
+        }
+    }
+    fn assume(&self, ctx: &Self::Context) {
+        if self.nondet {
+            cvlr_assume!(!self.range.is_empty());
+            (self.f)(nondet_in(self.range.clone())).assume(ctx);
+            return;
+        }
+        let mut last = None;
+        for i in self.range.clone() {
+            let g = (self.f)(i);
+            if g.eval(ctx) {
+                return;
+            }
+            last = Some(g);
+        }
+        match last {
+            Some(g) => g.assume(ctx),
+            None => cvlr_assume!(false),
+        }
+    }
+
+    fn eval_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) -> bool {
+        self.range
+            .clone()
+            .any(|i| (self.f)(i).eval_with_states(ctx0, ctx1))
+    }
+
+    fn assert_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        let mut last = None;
+        for i in self.range.clone() {
+            let g = (self.f)(i);
+            if g.eval_with_states(ctx0, ctx1) {
+                return;
+            }
+            last = Some(g);
+        }
+        match last {
+            Some(g) => g.assert_with_states(ctx0, ctx1),
+            None => cvlr_assert!(false),
+        }
+    }
+
+    fn assume_with_states(&self, ctx0: &Self::Context, ctx1: &Self::Context) {
+        if self.nondet {
+            cvlr_assume!(!self.range.is_empty());
+            (self.f)(nondet_in(self.range.clone())).assume_with_states(ctx0, ctx1);
+            return;
+        }
+        let mut last = None;
+        for i in self.range.clone() {
+            let g = (self.f)(i);
+            if g.eval_with_states(ctx0, ctx1) {
+                return;
+            }
+            last = Some(g);
+        }
+        match last {
+            Some(g) => g.assume_with_states(ctx0, ctx1),
+            None => cvlr_assume!(false),
+        }
+    }
+}
+
+/// Creates an existentially quantified expression over the indices in `range`.
+///
+/// `f` maps an index to the formula that may hold for it. The quantifier is
+/// unrolled over `range`, which should have constant bounds.
+///
+/// # Examples
+///
+/// ```
+/// use cvlr_spec::{cvlr_exists, cvlr_fn, CvlrFormula};
+///
+/// struct Book {
+///     orders: [u64; 3],
+/// }
+///
+/// let has_empty = cvlr_exists(0..3, |i| cvlr_fn(move |b: &Book| b.orders[i] == 0));
+/// assert!(has_empty.eval(&Book { orders: [5, 0, 7] }));
+/// assert!(!has_empty.eval(&Book { orders: [5, 6, 7] }));
+/// ```
+pub fn cvlr_exists<F, G>(range: Range<usize>, f: F) -> CvlrExists<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    CvlrExists {
+        range,
+        f,
+        nondet: false,
+    }
+}
+
+/// Creates an existentially quantified expression that is assumed for a nondet index.
+///
+/// Assuming the expression assumes `f(i)` for a single nondet `i` in `range`,
+/// the witness, so `range` may have symbolic bounds. Asserting the expression
+/// still evaluates every index in `range`.
+pub fn cvlr_exists_nondet<F, G>(range: Range<usize>, f: F) -> CvlrExists<F>
+where
+    F: Fn(usize) -> G,
+    G: CvlrFormula,
+{
+    CvlrExists {
+        range,
+        f,
+        nondet: true,
+    }
+}
@@ -8,6 +8,8 @@ use crate::formula::CvlrFormula;
 /// should:
 /// - Assume preconditions hold before an operation (via [`assume_requires`](CvlrSpec::assume_requires))
 /// - Check that postconditions hold after an operation (via [`check_ensures`](CvlrSpec::check_ensures))
+/// - Assert preconditions at a call replaced by the spec (via [`assert_requires`](CvlrSpec::assert_requires))
+/// - Assume postconditions after such a call (via [`assume_ensures`](CvlrSpec::assume_ensures))
 ///
 /// # Associated Types
 ///
@@ -51,6 +53,28 @@ pub trait CvlrSpec {
     /// * `post` - The post-state (state after the operation)
     /// * `old` - The pre-state (state before the operation)
     fn check_ensures(&self, post: &Self::Context, old: &Self::Context);
+
+    /// Asserts that the preconditions (requires) hold for the given pre-state.
+    ///
+    /// This is the caller's side of [`assume_requires`](CvlrSpec::assume_requires):
+    /// it is used where a call to the operation is replaced by its specification.
+    ///
+    /// # Arguments
+    ///
+    /// * `pre_state` - The state before the operation
+    fn assert_requires(&self, pre_state: &Self::Context);
+
+    /// Assumes that the postconditions (ensures) hold for the given pre/post state pair.
+    ///
+    /// This is the caller's side of [`check_ensures`](CvlrSpec::check_ensures).
+    /// A summary takes `old` by cloning the context before the call, so specs
+    /// used in summaries need a context that implements `Clone`.
+    ///
+    /// # Arguments
+    ///
+    /// * `post` - The post-state (state after the operation)
+    /// * `old` - The pre-state (state before the operation)
+    fn assume_ensures(&self, post: &Self::Context, old: &Self::Context);
 }
 
 /// An implementation of [`CvlrSpec`] that combines a precondition and postcondition.
@@ -75,6 +99,12 @@ where
     fn check_ensures(&self, post_state: &Self::Context, old: &Self::Context) {
         self.1.assert_with_states(post_state, old);
     }
+    fn assert_requires(&self, pre_state: &Self::Context) {
+        self.0.assert(pre_state);
+    }
+    fn assume_ensures(&self, post_state: &Self::Context, old: &Self::Context) {
+        self.1.assume_with_states(post_state, old);
+    }
 }
 
 /// Creates a specification from a precondition and postcondition.
@@ -159,6 +189,13 @@ where
         // -- invaraint is only over one-state so that it can be assumed in pre
         self.1.assert(post_state);
     }
+    fn assert_requires(&self, pre_state: &Self::Context) {
+        self.0.assert(pre_state);
+        self.1.assert(pre_state);
+    }
+    fn assume_ensures(&self, post_state: &Self::Context, _: &Self::Context) {
+        self.1.assume(post_state);
+    }
 }
 
 /// Creates an invariant specification from an assumption and an invariant.
@@ -174,8 +211,8 @@ where
 ///
 /// # Examples
 ///
-/// ```ignore
-/// use cvlr_spec::{cvlr_invar_spec, CvlrFormula};
+/// ```
+/// use cvlr_spec::{cvlr_fn, cvlr_invar_spec, CvlrFormula};
 ///
 /// struct Counter {
 ///     value: i32,
@@ -184,10 +221,11 @@ where
 /// // Create a spec with an assumption and an invariant
 /// let spec = cvlr_invar_spec(
 ///     // assumption: value is even
-///     |c: &Counter| c.value % 2 == 0,
+///     cvlr_fn(|c: &Counter| c.value % 2 == 0),
 ///     // invariant: value is non-negative
-///     |c: &Counter| c.value >= 0,
+///     cvlr_fn(|c: &Counter| c.value >= 0),
 /// );
+/// assert!(spec.invariant().eval(&Counter { value: 1 }));
 /// ```
 pub fn cvlr_invar_spec<A, B>(assumption: A, invariant: B) -> CvlrInvarSpec<A, B>
 where
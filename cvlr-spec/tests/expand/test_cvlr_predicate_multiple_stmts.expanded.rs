@@ -5,7 +5,7 @@ struct Ctx {
 }
 fn main() {
     let _ = {
-        #[allow(unused_must_use, dead_code)]
+        #[allow(unused_must_use, dead_code, clippy::no_effect)]
         fn __anonymous_predicate(c: &Ctx) {
             c.x > 0;
             c.y < 100;
@@ -193,7 +193,7 @@ fn test_state_pair_deref() {
     // Test tuple access
     assert_eq!(pair.0.x, 1);
     assert_eq!(pair.0.y, 2);
-    assert_eq!(pair.0.flag, true);
+    assert!(pair.0.flag);
 }
 
 #[test]
@@ -1957,3 +1957,368 @@ fn test_cvlr_lemma_new_branch_requires_ensures_interaction() {
     assert!(lemma.requires().eval(&ctx2));
     assert!(lemma.ensures().eval(&ctx2));
 }
+
+#[test]
+fn test_cvlr_or() {
+    let or_expr = cvlr_or(XPositive, YPositive);
+    let ctx = |x, y| TestCtx { x, y, flag: false };
+    assert!(or_expr.eval(&ctx(5, 10)));
+    assert!(or_expr.eval(&ctx(-1, 10)));
+    assert!(or_expr.eval(&ctx(5, -1)));
+    assert!(!or_expr.eval(&ctx(-1, -1)));
+
+    // second disjunct is only checked when the first one does not hold
+    or_expr.assert(&ctx(5, -1));
+    or_expr.assert(&ctx(-1, 10));
+    or_expr.assume(&ctx(5, -1));
+    or_expr.assume(&ctx(-1, 10));
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_or_assert_fails() {
+    let ctx = TestCtx {
+        x: -1,
+        y: -1,
+        flag: false,
+    };
+    cvlr_or(XPositive, YPositive).assert(&ctx);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_or_assume_fails() {
+    let ctx = TestCtx {
+        x: -1,
+        y: -1,
+        flag: false,
+    };
+    cvlr_or(XPositive, YPositive).assume(&ctx);
+}
+
+#[test]
+fn test_cvlr_or_macro() {
+    let ctx = TestCtx {
+        x: -1,
+        y: -1,
+        flag: true,
+    };
+    let expr = cvlr_or!(
+        XPositive,
+        YPositive,
+        cvlr_predicate! { | c : TestCtx | -> { c.flag; } }
+    );
+    assert!(expr.eval(&ctx));
+    expr.assert(&ctx);
+
+    let expr2 = cvlr_or!(XPositive, YPositive);
+    assert!(!expr2.eval(&ctx));
+}
+
+#[test]
+fn test_cvlr_not() {
+    let not_expr = cvlr_not(XPositive);
+    let pos = TestCtx {
+        x: 5,
+        y: 0,
+        flag: false,
+    };
+    let neg = TestCtx {
+        x: -5,
+        y: 0,
+        flag: false,
+    };
+    assert!(!not_expr.eval(&pos));
+    assert!(not_expr.eval(&neg));
+    not_expr.assert(&neg);
+    not_expr.assume(&neg);
+
+    let double = cvlr_not!(cvlr_not!(XPositive));
+    assert!(double.eval(&pos));
+    double.assert(&pos);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_not_assert_fails() {
+    let ctx = TestCtx {
+        x: 5,
+        y: 0,
+        flag: false,
+    };
+    cvlr_not!(XPositive).assert(&ctx);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_not_assume_fails() {
+    // -- assuming a negation assumes that the formula does not hold
+    let ctx = TestCtx {
+        x: 5,
+        y: 0,
+        flag: false,
+    };
+    cvlr_not!(XPositive).assume(&ctx);
+}
+
+#[test]
+fn test_cvlr_not_with_states() {
+    let pre = TestCtx {
+        x: 5,
+        y: 0,
+        flag: false,
+    };
+    let post = TestCtx {
+        x: 3,
+        y: 0,
+        flag: false,
+    };
+    let expr = cvlr_not(XIncreased);
+    assert!(expr.eval_with_states(&post, &pre));
+    assert!(!expr.eval_with_states(&pre, &post));
+    expr.assert_with_states(&post, &pre);
+    expr.assume_with_states(&post, &pre);
+}
+
+#[test]
+fn test_cvlr_iff() {
+    let iff_expr = cvlr_iff!(XPositive, YPositive);
+    let ctx = |x, y| TestCtx { x, y, flag: false };
+    assert!(iff_expr.eval(&ctx(5, 10)));
+    assert!(iff_expr.eval(&ctx(-1, -1)));
+    assert!(!iff_expr.eval(&ctx(5, -1)));
+    assert!(!iff_expr.eval(&ctx(-1, 10)));
+
+    iff_expr.assert(&ctx(5, 10));
+    iff_expr.assert(&ctx(-1, -1));
+    iff_expr.assume(&ctx(5, 10));
+    iff_expr.assume(&ctx(-1, -1));
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_iff_assert_fails() {
+    let ctx = TestCtx {
+        x: -1,
+        y: 10,
+        flag: false,
+    };
+    cvlr_iff(XPositive, YPositive).assert(&ctx);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_iff_assume_fails() {
+    let ctx = TestCtx {
+        x: -1,
+        y: 10,
+        flag: false,
+    };
+    cvlr_iff(XPositive, YPositive).assume(&ctx);
+}
+
+fn x_is_even(c: &TestCtx) -> bool {
+    c.x % 2 == 0
+}
+
+#[test]
+fn test_cvlr_fn() {
+    let ctx = TestCtx {
+        x: 4,
+        y: -1,
+        flag: false,
+    };
+    let closure = cvlr_fn(|c: &TestCtx| c.x > 0);
+    assert!(closure.eval(&ctx));
+    closure.assert(&ctx);
+    closure.assume(&ctx);
+
+    let func = cvlr_fn(x_is_even);
+    assert!(func.eval(&ctx));
+    assert!(func.tag().ends_with("x_is_even"));
+
+    let expr = cvlr_and!(cvlr_fn!(|c: &TestCtx| c.x > 0), YPositive);
+    assert!(!expr.eval(&ctx));
+}
+
+#[test]
+fn test_cvlr_fn_macro_tag() {
+    let expr = cvlr_fn!(|c: &TestCtx| c.x > 100);
+    assert_eq!(expr.tag(), "|c: &TestCtx| c.x > 100");
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: |c: &TestCtx| c.x > 100")]
+fn test_cvlr_fn_assert_fails_with_tag() {
+    let ctx = TestCtx {
+        x: 4,
+        y: 0,
+        flag: false,
+    };
+    cvlr_fn!(|c: &TestCtx| c.x > 100).assert(&ctx);
+}
+
+#[test]
+fn test_cvlr_fn_logs_tag() {
+    let ctx = TestCtx {
+        x: 4,
+        y: 0,
+        flag: false,
+    };
+    cvlr::log::rt::clear_trace();
+    cvlr_fn!(|c: &TestCtx| c.x > 0).assert(&ctx);
+    let trace = cvlr::log::rt::take_trace().to_string();
+    assert!(trace.contains("|c: &TestCtx| c.x > 0"), "{}", trace);
+}
+
+#[test]
+fn test_cvlr_fn2() {
+    let pre = TestCtx {
+        x: 1,
+        y: 0,
+        flag: false,
+    };
+    let post = TestCtx {
+        x: 2,
+        y: 0,
+        flag: false,
+    };
+    let increases = cvlr_fn2!(|c: &TestCtx, o: &TestCtx| c.x > o.x);
+    assert!(increases.eval_with_states(&post, &pre));
+    assert!(!increases.eval_with_states(&pre, &post));
+    increases.assert_with_states(&post, &pre);
+    increases.assume_with_states(&post, &pre);
+
+    let spec = cvlr_spec(cvlr_fn(|c: &TestCtx| c.x > 0), increases);
+    spec.assume_requires(&pre);
+    spec.check_ensures(&post, &pre);
+}
+
+#[test]
+fn test_cvlr_invar_spec_with_closures() {
+    let spec = cvlr_invar_spec(
+        cvlr_fn(|c: &TestCtx| c.x % 2 == 0),
+        cvlr_fn(|c: &TestCtx| c.x >= 0),
+    );
+    let pre = TestCtx {
+        x: 2,
+        y: 0,
+        flag: false,
+    };
+    let post = TestCtx {
+        x: 3,
+        y: 0,
+        flag: false,
+    };
+    spec.assume_requires(&pre);
+    spec.check_ensures(&post, &pre);
+}
+
+struct Vault {
+    balances: [u64; 4],
+    len: usize,
+    cap: u64,
+}
+
+fn capped(i: usize) -> impl CvlrFormula<Context = Vault> {
+    cvlr_fn(move |v: &Vault| v.balances[i] <= v.cap)
+}
+
+fn empty_at(i: usize) -> impl CvlrFormula<Context = Vault> {
+    cvlr_fn(move |v: &Vault| v.balances[i] == 0)
+}
+
+#[test]
+fn test_cvlr_forall() {
+    let v = Vault {
+        balances: [1, 2, 3, 9],
+        len: 3,
+        cap: 3,
+    };
+    assert!(cvlr_forall(0..3, capped).eval(&v));
+    assert!(!cvlr_forall(0..4, capped).eval(&v));
+    assert!(cvlr_forall(0..0, capped).eval(&v));
+    cvlr_forall(0..3, capped).assert(&v);
+    cvlr_forall(0..3, capped).assume(&v);
+    cvlr_forall(0..0, capped).assert(&v);
+
+    let sym = cvlr_forall_nondet(0..v.len, capped);
+    assert!(sym.eval(&v));
+    sym.assert(&v);
+    sym.assume(&v);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_forall_assert_fails() {
+    let v = Vault {
+        balances: [1, 2, 3, 9],
+        len: 4,
+        cap: 3,
+    };
+    cvlr_forall(0..v.len, capped).assert(&v);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_forall_assume_fails() {
+    let v = Vault {
+        balances: [1, 2, 3, 9],
+        len: 4,
+        cap: 3,
+    };
+    cvlr_forall_nondet(0..v.len, capped).assume(&v);
+}
+
+#[test]
+fn test_cvlr_exists() {
+    let v = Vault {
+        balances: [1, 0, 3, 0],
+        len: 4,
+        cap: 3,
+    };
+    assert!(cvlr_exists(0..4, empty_at).eval(&v));
+    assert!(!cvlr_exists(0..1, empty_at).eval(&v));
+    assert!(!cvlr_exists(0..0, empty_at).eval(&v));
+    cvlr_exists(0..4, empty_at).assert(&v);
+    cvlr_exists(0..4, empty_at).assume(&v);
+    cvlr_exists_nondet(0..v.len, empty_at).assert(&v);
+
+    // -- the witness is checked for a single nondet index
+    let report = cvlr::rt::run_rule(
+        || {
+            let v = Vault {
+                balances: [1, 0, 3, 0],
+                len: 4,
+                cap: 3,
+            };
+            cvlr_exists_nondet(0..v.len, empty_at).assume(&v);
+        },
+        20,
+    );
+    report.assert_success();
+    assert!(report.passed > 0);
+    assert!(report.discarded > 0);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_exists_assert_fails() {
+    let v = Vault {
+        balances: [1, 2, 3, 4],
+        len: 4,
+        cap: 3,
+    };
+    cvlr_exists(0..v.len, empty_at).assert(&v);
+}
+
+#[test]
+#[should_panic]
+fn test_cvlr_exists_empty_assume_fails() {
+    let v = Vault {
+        balances: [0; 4],
+        len: 0,
+        cap: 3,
+    };
+    cvlr_exists(0..v.len, empty_at).assume(&v);
+}
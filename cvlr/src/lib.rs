@@ -1,7 +1,14 @@
 #![no_std]
 
+#[cfg(feature = "rt")]
+extern crate std;
+
+pub mod manifest;
 pub mod u128_arith;
 
+#[cfg(feature = "rt")]
+pub mod rt;
+
 pub mod asserts {
     pub use cvlr_asserts::*;
 }
@@ -61,7 +68,7 @@ pub mod prelude {
         cvlr_eval_that,
     };
 
-    pub use super::derive::{CvlrLog, Nondet};
+    pub use super::derive::{CvlrFrame, CvlrLog, Nondet};
 
     pub use super::spec::*;
 }
@@ -71,3 +78,4 @@ pub use prelude::*;
 pub use crate::mathint::{is_u128, is_u16, is_u32, is_u64, is_u8};
 pub use macros::cvlr_pif as pif;
 pub use macros::cvlr_predicate as predicate;
+pub use macros::{ensures, requires, summary};
@@ -0,0 +1,203 @@
+//! Manifest of the rules of a crate
+//!
+//! Every `#[rule]`, and every rule generated by `cvlr_rules!` or
+//! `cvlr_invariant_rules!`, registers a [`RuleEntry`] in a link-time table,
+//! the `cvlr_rules` section of the binary. [`rules`] lists the entries that are
+//! linked in, and [`write_json`] dumps them, for example to generate prover
+//! configurations:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "rules": [
+//!     { "name": "rule_solvency", "file": "src/certora/spec.rs", "line": 10,
+//!       "tags": ["solvency"] }
+//!   ]
+//! }
+//! ```
+//!
+//! The table is only available on Linux, Android, and FreeBSD targets. On
+//! other targets, including Solana, registration expands to nothing, so that
+//! verified programs are unchanged, and [`rules`] is empty.
+//!
+//! # Example
+//!
+//! A binary that prints the manifest of the rules of `my_spec`:
+//!
+//! ```ignore
+//! // -- make sure the rules are linked in
+//! extern crate my_spec;
+//!
+//! fn main() {
+//!     let mut out = String::new();
+//!     cvlr::manifest::write_json(&mut out).unwrap();
+//!     println!("{}", out);
+//! }
+//! ```
+
+use core::fmt::{self, Write};
+
+/// A registered rule
+#[derive(Debug)]
+#[repr(C)]
+pub struct RuleEntry {
+    /// Name of the rule, which is the name of its function
+    pub name: &'static str,
+    /// File that defines the rule
+    pub file: &'static str,
+    /// Line that defines the rule
+    pub line: u32,
+    /// Tags of the rule
+    pub tags: &'static [&'static str],
+}
+
+/// Registers a rule in the manifest
+///
+/// `#[rule]` registers the functions it marks; this is only needed for rules
+/// defined by other means.
+///
+/// ```ignore
+/// cvlr::cvlr_register_rule!(rule_solvency, tags: ["solvency"]);
+/// ```
+#[macro_export]
+macro_rules! cvlr_register_rule {
+    ($name:ident) => {
+        $crate::cvlr_register_rule!($name, tags: []);
+    };
+    ($name:ident, tags: [ $( $tag:literal ),* $(,)? ]) => {
+        const _: () = {
+            $crate::__cvlr_rule_section! {
+                static __CVLR_RULE: $crate::manifest::RuleEntry = $crate::manifest::RuleEntry {
+                    name: ::core::stringify!($name),
+                    file: $crate::log::cvlr_log_core_file!(),
+                    line: $crate::log::cvlr_log_core_line!(),
+                    tags: &[ $( $tag ),* ],
+                };
+            }
+        };
+    };
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+mod section {
+    use super::RuleEntry;
+
+    /// Places a static in the `cvlr_rules` section
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __cvlr_rule_section {
+        ($item:item) => {
+            #[used]
+            #[link_section = "cvlr_rules"]
+            $item
+        };
+    }
+
+    // -- an empty entry keeps the section, and its bounds, defined in binaries
+    // -- without rules
+    #[used]
+    #[link_section = "cvlr_rules"]
+    static EMPTY: [RuleEntry; 0] = [];
+
+    extern "Rust" {
+        #[link_name = "__start_cvlr_rules"]
+        static START: RuleEntry;
+        #[link_name = "__stop_cvlr_rules"]
+        static STOP: RuleEntry;
+    }
+
+    pub fn rules() -> &'static [RuleEntry] {
+        // SAFETY: the linker places every `RuleEntry` of the `cvlr_rules`
+        // section, and nothing else, between the two bounds
+        unsafe {
+            let start = core::ptr::addr_of!(START);
+            let stop = core::ptr::addr_of!(STOP);
+            let len = (stop as usize - start as usize) / core::mem::size_of::<RuleEntry>();
+            core::slice::from_raw_parts(start, len)
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd")))]
+mod section {
+    use super::RuleEntry;
+
+    /// Drops the static, without a table to collect it into
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __cvlr_rule_section {
+        ($item:item) => {};
+    }
+
+    pub fn rules() -> &'static [RuleEntry] {
+        &[]
+    }
+}
+
+/// Rules registered in the current binary, in link order
+///
+/// A rule may be registered more than once, for example by `cvlr_rules!` and
+/// by a `#[rule]` that it expands to.
+pub fn rules() -> &'static [RuleEntry] {
+    section::rules()
+}
+
+fn write_str(out: &mut impl Write, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+/// Writes the manifest of [`rules`] as JSON
+///
+/// Every rule appears once, at its first registration, with the tags of all
+/// its registrations.
+pub fn write_json(out: &mut impl Write) -> fmt::Result {
+    let rules = rules();
+    out.write_str("{\n  \"version\": 1,\n  \"rules\": [")?;
+    let mut first = true;
+    for (i, rule) in rules.iter().enumerate() {
+        if rules[..i].iter().any(|r| r.name == rule.name) {
+            continue;
+        }
+        if !first {
+            out.write_char(',')?;
+        }
+        first = false;
+        out.write_str("\n    { \"name\": ")?;
+        write_str(out, rule.name)?;
+        out.write_str(", \"file\": ")?;
+        write_str(out, rule.file)?;
+        write!(out, ", \"line\": {}, \"tags\": [", rule.line)?;
+        let same = rules.iter().filter(|r| r.name == rule.name);
+        let mut first_tag = true;
+        for (j, tag) in same.clone().flat_map(|r| r.tags.iter()).enumerate() {
+            if same
+                .clone()
+                .flat_map(|r| r.tags.iter())
+                .take(j)
+                .any(|t| t == tag)
+            {
+                continue;
+            }
+            if !first_tag {
+                out.write_str(", ")?;
+            }
+            first_tag = false;
+            write_str(out, tag)?;
+        }
+        out.write_str("] }")?;
+    }
+    if !first {
+        out.write_str("\n  ")?;
+    }
+    out.write_str("]\n}\n")
+}
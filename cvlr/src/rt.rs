@@ -0,0 +1,181 @@
+//! Running rules concretely under the `rt` feature
+//!
+//! A rule is run repeatedly, each time with nondet values drawn from a
+//! different seed. A run that violates an assumption is discarded; it is a
+//! vacuous pass, not a counterexample. Any other panic is a failure, reported
+//! with the seed that reproduces it and the calltrace of the failing run.
+
+use std::{
+    any::Any,
+    boxed::Box,
+    cell::Cell,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    string::{String, ToString},
+    sync::Once,
+    vec::Vec,
+};
+
+pub use cvlr_asserts::AssumptionViolated;
+pub use cvlr_log::rt::Trace;
+pub use cvlr_nondet::rt::*;
+
+/// A failed run of a rule
+#[derive(Debug, Clone)]
+pub struct RuleFailure {
+    /// Index of the run
+    pub run: usize,
+    /// Seed with which the failure is reproduced
+    pub seed: u64,
+    /// Panic message
+    pub message: String,
+    /// Calltrace recorded by the failing run
+    pub trace: Trace,
+}
+
+/// Outcome of [run_rule]
+#[derive(Debug, Clone, Default)]
+pub struct RuleReport {
+    pub runs: usize,
+    pub passed: usize,
+    /// Runs that violated an assumption
+    pub discarded: usize,
+    pub failures: Vec<RuleFailure>,
+}
+
+impl RuleReport {
+    /// No run failed
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Every run violated an assumption, so the rule was never exercised
+    pub fn is_vacuous(&self) -> bool {
+        self.runs > 0 && self.discarded == self.runs
+    }
+
+    /// Panics with a summary of the report unless no run failed
+    pub fn assert_success(&self) {
+        if !self.is_success() {
+            panic!("{}", self);
+        }
+    }
+}
+
+impl fmt::Display for RuleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} runs: {} passed, {} discarded, {} failed",
+            self.runs,
+            self.passed,
+            self.discarded,
+            self.failures.len()
+        )?;
+        for failure in &self.failures {
+            write!(
+                f,
+                "\n  run {} (seed {}): {}",
+                failure.run, failure.seed, failure.message
+            )?;
+            for line in failure.trace.to_string().lines() {
+                write!(f, "\n    {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+std::thread_local! {
+    static IN_RUN_RULE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Silences the panic message of discarded runs, leaving other panics as is
+fn install_panic_hook() {
+    static ONCE: Once = Once::new();
+    ONCE.call_once(|| {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let discarded = AssumptionViolated::is_payload(info.payload());
+            if !(discarded && IN_RUN_RULE.with(Cell::get)) {
+                prev(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
+
+/// Run `rule` `runs` times, each with a different seed
+///
+/// Seeds start at the current seed (e.g., from `CVLR_SEED`), or at 0, and
+/// increase by one for every run. The seed in effect before the call is
+/// restored afterwards.
+///
+/// While a counterexample is replayed (see [replay_file]), the rule is run only
+/// once: the replay supplies the values of a single run, and seeds are ignored.
+///
+/// # Example
+///
+/// ```
+/// use cvlr::prelude::*;
+///
+/// fn rule_add_is_monotone() {
+///     let x: u64 = nondet();
+///     let y: u64 = nondet();
+///     cvlr_assume!(x.checked_add(y).is_some());
+///     cvlr_assert!(x + y >= x);
+/// }
+///
+/// let report = cvlr::rt::run_rule(rule_add_is_monotone, 100);
+/// report.assert_success();
+/// assert_eq!(report.passed + report.discarded, 100);
+/// ```
+pub fn run_rule<F: Fn()>(rule: F, runs: usize) -> RuleReport {
+    install_panic_hook();
+    let prev_seed = seed();
+    let base = prev_seed.unwrap_or(0);
+    // -- a replay is consumed by the first run, later runs would only exhaust it
+    let runs = if replay_remaining().is_some() {
+        runs.min(1)
+    } else {
+        runs
+    };
+    let mut report = RuleReport {
+        runs,
+        ..Default::default()
+    };
+
+    IN_RUN_RULE.with(|c| c.set(true));
+    for run in 0..runs {
+        let seed = base.wrapping_add(run as u64);
+        set_seed(seed);
+        cvlr_log::rt::clear_trace();
+        match panic::catch_unwind(AssertUnwindSafe(&rule)) {
+            Ok(()) => report.passed += 1,
+            Err(payload) if AssumptionViolated::is_payload(&*payload) => report.discarded += 1,
+            Err(payload) => report.failures.push(RuleFailure {
+                run,
+                seed,
+                message: panic_message(&*payload),
+                trace: cvlr_log::rt::take_trace(),
+            }),
+        }
+    }
+    cvlr_log::rt::clear_trace();
+    IN_RUN_RULE.with(|c| c.set(false));
+
+    match prev_seed {
+        Some(s) => set_seed(s),
+        None => clear_seed(),
+    }
+    report
+}
@@ -0,0 +1,93 @@
+//! Runtime tests for `#[cvlr::requires]` and `#[cvlr::ensures]`
+use cvlr::prelude::*;
+use cvlr::rt::run_rule;
+
+#[cvlr::requires(amount <= balance, when = "rt")]
+#[cvlr::ensures(result == old(balance) - amount)]
+fn withdraw(balance: u64, amount: u64) -> u64 {
+    balance - amount
+}
+
+#[cvlr::ensures(result <= 10, when = "rt")]
+fn buggy_cap(x: u8) -> u8 {
+    if x > 100 {
+        return x;
+    }
+    x.min(10)
+}
+
+struct Vault {
+    shares: u64,
+}
+
+impl Vault {
+    #[cvlr::ensures(self.shares == old(self.shares) + amount, when = "rt")]
+    #[cvlr::ensures(result.is_ok())]
+    fn deposit(&mut self, amount: u64) -> Result<(), ()> {
+        self.shares = self.shares.checked_add(amount).ok_or(())?;
+        Ok(())
+    }
+
+    #[cvlr::ensures(*result >= 1, when = "rt")]
+    fn shares_mut(&mut self) -> &mut u64 {
+        if self.shares == 0 {
+            self.shares = 1;
+        }
+        &mut self.shares
+    }
+}
+
+#[cvlr::ensures(result.len() == n, when = "vacuity")]
+fn zeros(n: usize) -> Vec<u8> {
+    vec![0; n + 1]
+}
+
+#[test]
+fn test_requires_is_assumed() {
+    run_rule(
+        || {
+            let balance: u64 = nondet();
+            let amount: u64 = nondet();
+            withdraw(balance, amount);
+        },
+        200,
+    )
+    .assert_success();
+
+    let payload = std::panic::catch_unwind(|| withdraw(1, 2)).unwrap_err();
+    assert!(cvlr::rt::AssumptionViolated::is_payload(&*payload));
+}
+
+#[test]
+fn test_ensures_on_early_return() {
+    assert_eq!(buggy_cap(50), 10);
+    let report = run_rule(
+        || {
+            buggy_cap(nondet());
+        },
+        200,
+    );
+    assert!(!report.is_success());
+}
+
+#[test]
+fn test_ensures_with_self_and_question_mark() {
+    let mut vault = Vault { shares: 5 };
+    assert_eq!(vault.deposit(3), Ok(()));
+    assert_eq!(vault.shares, 8);
+
+    let mut vault = Vault { shares: u64::MAX };
+    assert!(std::panic::catch_unwind(move || vault.deposit(1)).is_err());
+}
+
+#[test]
+fn test_untouched_without_feature() {
+    assert_eq!(zeros(2).len(), 3);
+}
+
+#[test]
+fn test_ensures_with_borrowed_result() {
+    let mut vault = Vault { shares: 0 };
+    *vault.shares_mut() += 1;
+    assert_eq!(vault.shares, 2);
+}
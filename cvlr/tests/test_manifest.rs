@@ -0,0 +1,95 @@
+//! Tests for the rule manifest
+use cvlr::manifest::{rules, write_json};
+use cvlr::prelude::*;
+
+#[rule]
+fn rule_plain() {
+    cvlr_assert!(true);
+}
+
+#[rule(tags = ["solvency", "fast"])]
+fn rule_tagged(x: u8) {
+    cvlr_assert!(x.checked_add(0).is_some());
+}
+
+fn base_deposit() {}
+
+macro_rules! cvlr_impl_rule {
+    {$rule_name:ident, $spec:expr, $base:ident} => {
+        #[rule(tags = ["generated"])]
+        fn $rule_name() {
+            $base();
+        }
+    };
+}
+
+cvlr_rules! {
+    name: "solvency",
+    spec: cvlr_spec(cvlr_true::<u8>(), cvlr_true::<u8>()),
+    bases: [base_deposit]
+}
+
+cvlr::cvlr_register_rule!(rule_external, tags: ["a \"quoted\" tag"]);
+
+#[test]
+fn test_rules_are_registered() {
+    let mut names: Vec<_> = rules().iter().map(|r| r.name).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(
+        names,
+        [
+            "rule_external",
+            "rule_plain",
+            "rule_tagged",
+            "solvency_deposit"
+        ]
+    );
+
+    let tagged = rules().iter().find(|r| r.name == "rule_tagged").unwrap();
+    // -- locations are omitted under `no-loc`, which other crates of the
+    // -- workspace enable
+    assert_eq!(tagged.file, cvlr::log::cvlr_log_core_file!());
+    assert!(tagged.line == 10 || tagged.file == "<FILE>");
+    assert_eq!(tagged.tags, ["solvency", "fast"]);
+
+    rule_plain();
+    solvency_deposit();
+}
+
+#[test]
+fn test_write_json() {
+    let mut out = String::new();
+    write_json(&mut out).unwrap();
+    assert!(
+        out.starts_with("{\n  \"version\": 1,\n  \"rules\": [\n"),
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("\"tags\": [\"solvency\", \"fast\"]"),
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("\"tags\": [\"a \\\"quoted\\\" tag\"]"),
+        "{}",
+        out
+    );
+    // -- a rule registered by `cvlr_rules!` and by `#[rule]` is listed once,
+    // -- with all its tags
+    assert_eq!(
+        out.matches("\"name\": \"solvency_deposit\"").count(),
+        1,
+        "{}",
+        out
+    );
+    assert!(
+        out.contains("\"solvency\", \"generated\"") || out.contains("\"generated\", \"solvency\""),
+        "{}",
+        out
+    );
+
+    let rule_plain = out.lines().find(|l| l.contains("\"rule_plain\"")).unwrap();
+    assert!(rule_plain.contains("\"tags\": [] }"), "{}", rule_plain);
+}
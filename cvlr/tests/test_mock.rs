@@ -0,0 +1,93 @@
+//! Runtime tests for `#[mock_fn]`
+use cvlr::prelude::*;
+
+fn mock_double(x: u64) -> u64 {
+    x + 1000
+}
+
+#[mock_fn(with = mock_double, when = "rt")]
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+#[mock_fn(with = mock_double, when = not(feature = "rt"))]
+fn double_unmocked(x: u64) -> u64 {
+    x * 2
+}
+
+#[mock_fn(with = mock_double, when = all(feature = "rt", not(feature = "vacuity")))]
+fn double_all(mut x: u64) -> u64 {
+    x *= 2;
+    x
+}
+
+struct Vault {
+    shares: u64,
+}
+
+fn mock_new() -> Vault {
+    Vault { shares: 7 }
+}
+
+fn mock_deposit(vault: &mut Vault, amount: u64) -> u64 {
+    vault.shares = amount;
+    0
+}
+
+impl Vault {
+    #[mock_fn(with = mock_new, when = "rt")]
+    fn new() -> Self {
+        Vault { shares: 0 }
+    }
+
+    #[mock_fn(with = mock_deposit, when = "rt")]
+    fn deposit(&mut self, amount: u64) -> u64 {
+        self.shares += amount;
+        self.shares
+    }
+}
+
+trait Shares {
+    fn total(&self) -> u64;
+}
+
+fn mock_total<T>(_: &T) -> u64 {
+    42
+}
+
+impl Shares for Vault {
+    #[mock_fn(with = mock_total, when = "rt")]
+    fn total(&self) -> u64 {
+        self.shares
+    }
+}
+
+fn mock_first<T: Copy>(items: &[T], _: (usize, usize)) -> T {
+    items[items.len() - 1]
+}
+
+#[mock_fn(with = mock_first, when = "rt")]
+fn first<T: Copy>(items: &[T], (lo, _hi): (usize, usize)) -> T {
+    items[lo]
+}
+
+#[test]
+fn test_mock_free_fn() {
+    assert_eq!(double(1), 1001);
+    assert_eq!(double_unmocked(1), 2);
+    assert_eq!(double_all(1), 1001);
+}
+
+#[test]
+fn test_mock_methods() {
+    let mut vault = Vault::new();
+    assert_eq!(vault.shares, 7);
+    assert_eq!(vault.deposit(3), 0);
+    assert_eq!(vault.shares, 3);
+    assert_eq!(vault.total(), 42);
+}
+
+#[test]
+fn test_mock_generic_fn() {
+    assert_eq!(first(&[1u8, 2, 3], (0, 3)), 3);
+}
@@ -0,0 +1,92 @@
+use cvlr::prelude::*;
+use cvlr::rt::{run_rule, AssumptionViolated};
+
+fn rule_discards_odd() {
+    let x: u64 = nondet();
+    cvlr_assume!(x.is_multiple_of(2));
+    cvlr_assert!(x.is_multiple_of(2));
+}
+
+fn rule_fails_on_large() {
+    let x: u8 = nondet();
+    cvlr_assert!(x < 200);
+}
+
+#[test]
+fn test_assume_payload() {
+    let payload = std::panic::catch_unwind(|| cvlr_assume!(false)).unwrap_err();
+    assert!(AssumptionViolated::is_payload(&*payload));
+
+    let payload = std::panic::catch_unwind(|| cvlr_assert!(false)).unwrap_err();
+    assert!(!AssumptionViolated::is_payload(&*payload));
+}
+
+#[test]
+fn test_run_rule_discards() {
+    let report = run_rule(rule_discards_odd, 200);
+    assert!(report.is_success());
+    assert!(!report.is_vacuous());
+    assert_eq!(report.runs, 200);
+    assert_eq!(report.passed + report.discarded, 200);
+    assert!(report.discarded > 0);
+    assert!(report.passed > 0);
+}
+
+#[test]
+fn test_run_rule_vacuous() {
+    let report = run_rule(|| cvlr_assume!(false), 10);
+    assert!(report.is_success());
+    assert!(report.is_vacuous());
+    assert_eq!(report.discarded, 10);
+}
+
+#[test]
+fn test_run_rule_failures_are_reproducible() {
+    cvlr::rt::set_seed(1000);
+    let report = run_rule(rule_fails_on_large, 100);
+    assert!(!report.is_success());
+    assert_eq!(report.passed + report.failures.len(), 100);
+    assert_eq!(cvlr::rt::seed(), Some(1000));
+
+    let failure = &report.failures[0];
+    assert_eq!(failure.seed, 1000 + failure.run as u64);
+    cvlr::rt::set_seed(failure.seed);
+    let x: u8 = nondet();
+    assert!(x >= 200);
+    cvlr::rt::clear_seed();
+}
+
+#[test]
+#[should_panic(expected = "100 runs: 0 passed, 0 discarded, 100 failed")]
+fn test_run_rule_assert_success() {
+    run_rule(|| cvlr_assert!(false), 100).assert_success();
+}
+
+#[test]
+fn test_run_rule_failure_trace() {
+    let report = run_rule(
+        || {
+            let x: u64 = 7;
+            clog!(x);
+            cvlr_assert_eq!(x, 8, "x is eight");
+        },
+        1,
+    );
+    let failure = &report.failures[0];
+    assert_eq!(failure.message, "assertion failed: x is eight");
+    let trace = failure.trace.to_string();
+    assert!(trace.contains("x: 7"));
+    assert!(trace.contains("assert {"));
+    assert!(trace.contains("desc: \"x is eight\""));
+    assert!(report.to_string().contains("\n    x: 7"));
+}
+
+#[test]
+fn test_run_rule_replays_once() {
+    cvlr::rt::replay_str("u8 250").unwrap();
+    let report = run_rule(rule_fails_on_large, 100);
+    assert_eq!(report.runs, 1);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(cvlr::rt::replay_remaining(), Some(0));
+    cvlr::rt::clear_replay();
+}
@@ -0,0 +1,48 @@
+//! Runtime tests for `#[rule]` with parameters
+use cvlr::prelude::*;
+use cvlr::rt::run_rule;
+
+#[derive(Nondet, CvlrLog)]
+struct Account {
+    balance: u64,
+}
+
+#[rule]
+fn rule_add_is_monotone(x: u64, y: u64) {
+    cvlr_assume!(x.checked_add(y).is_some());
+    cvlr_assert!(x + y >= x);
+}
+
+#[rule]
+fn rule_deposit(acc: &mut Account, mut amount: u64, Account { balance: fee }: Account) {
+    amount %= 1000;
+    cvlr_assume!(acc.balance < u64::MAX / 2);
+    let before = acc.balance;
+    acc.balance += amount + fee % 1000;
+    cvlr_assert!(acc.balance >= before);
+}
+
+#[rule]
+fn rule_buggy(x: u8) {
+    cvlr_assert!(x < 200);
+}
+
+#[test]
+fn test_rule_params() {
+    let report = run_rule(rule_add_is_monotone, 100);
+    report.assert_success();
+    assert!(report.passed > 0);
+    run_rule(rule_deposit, 100).assert_success();
+}
+
+#[test]
+fn test_rule_params_are_logged() {
+    cvlr::log::rt::clear_trace();
+    rule_deposit();
+    let trace = cvlr::log::rt::take_trace().to_string();
+    assert!(trace.contains("acc"), "{}", trace);
+    assert!(trace.contains("amount"), "{}", trace);
+    assert!(trace.contains("fee"), "{}", trace);
+
+    assert!(!run_rule(rule_buggy, 200).is_success());
+}